@@ -0,0 +1,346 @@
+//! Localized string resources keyed by `Locale`
+//!
+//! Strings are loaded per locale tag from JSON (or TOML, with the `toml` feature) tables.
+//! A table maps keys to a string, a list of variants (one is picked at random),
+//! or plural forms keyed by CLDR category (`zero`, `one`, `two`, `few`, `many`, `other`):
+//!
+//! ```json
+//! {
+//!     "GREETING": ["Hello {name}", "Hi {name}"],
+//!     "ITEMS": { "one": "{count} item", "other": "{count} items" }
+//! }
+//! ```
+//!
+//! Tables are added from strings, which can be embedded at compile time with
+//! `include_str!("../i18n/en-US.json")`:
+//!
+//! ```rust
+//! # use alexa_sdk::i18n::{Error, Resources};
+//! # fn main() -> Result<(), Error> {
+//! Resources::new()
+//!     .add_json("en-US", r#"{"GREETING": ["Hello {name}", "Hi {name}"]}"#)?
+//!     .add_json("fr", r#"{"GREETING": "Bonjour {name}"}"#)?
+//!     .default_locale("en-US")
+//!     .install();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Keys are resolved through `Locale::fallback_chain` and then the default locale,
+//! so `fr-CA` looks in `fr-CA`, then `fr`, then `en-US`.
+
+extern crate serde_derive;
+extern crate serde_json;
+#[cfg(feature = "toml")]
+extern crate toml;
+
+use self::serde_derive::Deserialize;
+use crate::request::Locale;
+use crate::util::random_index;
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+static INSTALLED: OnceLock<Resources> = OnceLock::new();
+
+/// Errors loading string tables
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Json(serde_json::Error),
+    #[cfg(feature = "toml")]
+    Toml(toml::de::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref e) => write!(f, "error reading string table: {}", e),
+            Error::Json(ref e) => write!(f, "error parsing JSON string table: {}", e),
+            #[cfg(feature = "toml")]
+            Error::Toml(ref e) => write!(f, "error parsing TOML string table: {}", e),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Error {
+        Error::Json(e)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Error {
+        Error::Toml(e)
+    }
+}
+
+/// A localized message: a single string, or variants to pick from at random
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+enum Message {
+    Text(String),
+    Variants(Vec<String>),
+}
+
+/// An entry of a string table, either a message or plural forms of a message
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+enum Entry {
+    Message(Message),
+    Plural(HashMap<String, Message>),
+}
+
+/// CLDR plural categories
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl PluralCategory {
+    /// returns the plural category of an integer count for the language of a locale
+    pub fn for_count(locale: &Locale, n: i64) -> PluralCategory {
+        let n = n.abs();
+        match locale.language() {
+            "ja" => PluralCategory::Other,
+            "fr" | "hi" | "pt" if locale != &Locale::Portuguese => {
+                if n <= 1 {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+            "ar" => match (n, n % 100) {
+                (0, _) => PluralCategory::Zero,
+                (1, _) => PluralCategory::One,
+                (2, _) => PluralCategory::Two,
+                (_, 3..=10) => PluralCategory::Few,
+                (_, 11..=99) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            _ => {
+                if n == 1 {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+        }
+    }
+}
+
+/// Localized string tables for a set of locales
+#[derive(Debug, Clone, Default)]
+pub struct Resources {
+    tables: HashMap<String, HashMap<String, Entry>>,
+    default_locale: Option<String>,
+}
+
+impl Resources {
+    /// Constructs an empty set of resources
+    pub fn new() -> Resources {
+        Resources::default()
+    }
+
+    /// adds a JSON string table for the given locale or language tag
+    pub fn add_json(mut self, tag: &str, json: &str) -> Result<Self, Error> {
+        let table = serde_json::from_str(json)?;
+        self.add_table(tag, table);
+        Ok(self)
+    }
+
+    /// adds a TOML string table for the given locale or language tag
+    #[cfg(feature = "toml")]
+    pub fn add_toml(mut self, tag: &str, s: &str) -> Result<Self, Error> {
+        let table = toml::from_str(s)?;
+        self.add_table(tag, table);
+        Ok(self)
+    }
+
+    /// loads all string tables in a directory, named by locale or language tag,
+    /// e.g. `en-US.json` or `fr.toml`
+    pub fn load_dir<P: AsRef<Path>>(mut self, dir: P) -> Result<Self, Error> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let tag = match path.file_stem().and_then(|s| s.to_str()) {
+                Some(t) => t.to_string(),
+                None => continue,
+            };
+            match path.extension().and_then(|s| s.to_str()) {
+                Some("json") => self = self.add_json(&tag, &fs::read_to_string(&path)?)?,
+                #[cfg(feature = "toml")]
+                Some("toml") => self = self.add_toml(&tag, &fs::read_to_string(&path)?)?,
+                _ => {}
+            }
+        }
+        Ok(self)
+    }
+
+    /// sets the locale or language tag used when a key is not found for the request locale
+    pub fn default_locale(mut self, tag: &str) -> Self {
+        self.default_locale = Some(String::from(tag));
+        self
+    }
+
+    /// installs these resources for use by `Request::t`
+    /// resources can only be installed once: the first install wins, and later calls
+    /// return false, leaving the installed resources unchanged
+    /// use `Request::t_with` to look up strings in other resources
+    pub fn install(self) -> bool {
+        INSTALLED.set(self).is_ok()
+    }
+
+    /// returns the installed resources, if any
+    pub fn installed() -> Option<&'static Resources> {
+        INSTALLED.get()
+    }
+
+    fn add_table(&mut self, tag: &str, table: HashMap<String, Entry>) {
+        self.tables
+            .entry(String::from(tag))
+            .or_default()
+            .extend(table);
+    }
+
+    fn lookup(&self, locale: &Locale, key: &str) -> Option<&Entry> {
+        locale
+            .fallback_chain()
+            .into_iter()
+            .chain(self.default_locale.as_deref())
+            .filter_map(|tag| self.tables.get(tag)?.get(key))
+            .next()
+    }
+
+    /// returns the localized string for a key, with `{name}` placeholders replaced by args
+    /// plural forms are selected using the `count` arg
+    /// returns `None` if the key is not found in the locale fallback chain
+    pub fn get(&self, locale: &Locale, key: &str, args: &[(&str, &str)]) -> Option<String> {
+        let message = match self.lookup(locale, key)? {
+            Entry::Message(m) => m,
+            Entry::Plural(forms) => {
+                let count = args
+                    .iter()
+                    .find(|(k, _)| *k == "count")
+                    .and_then(|(_, v)| v.parse().ok())
+                    .unwrap_or(0);
+                let category = PluralCategory::for_count(locale, count);
+                forms
+                    .get(&category.to_string())
+                    .or_else(|| forms.get("other"))?
+            }
+        };
+        let text = match message {
+            Message::Text(s) => s,
+            Message::Variants(v) => v.get(random_index(v.len()))?,
+        };
+        Some(interpolate(text, args))
+    }
+}
+
+fn interpolate(text: &str, args: &[(&str, &str)]) -> String {
+    args.iter().fold(String::from(text), |s, (k, v)| {
+        s.replace(&format!("{{{}}}", k), v)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resources() -> Resources {
+        Resources::new()
+            .add_json(
+                "en-US",
+                r#"{
+                    "HELLO": "Hello {name}",
+                    "BYE": ["Bye", "See you"],
+                    "ITEMS": { "one": "{count} item", "other": "{count} items" }
+                }"#,
+            )
+            .unwrap()
+            .add_json(
+                "fr",
+                r#"{
+                    "HELLO": "Bonjour {name}",
+                    "ITEMS": { "one": "{count} article", "other": "{count} articles" }
+                }"#,
+            )
+            .unwrap()
+            .default_locale("en-US")
+    }
+
+    #[test]
+    fn test_fallback() {
+        let r = resources();
+        let args = [("name", "Bob")];
+        assert_eq!(
+            r.get(&Locale::CanadianFrench, "HELLO", &args),
+            Some(String::from("Bonjour Bob"))
+        );
+        assert_eq!(
+            r.get(&Locale::German, "HELLO", &args),
+            Some(String::from("Hello Bob"))
+        );
+        assert_eq!(r.get(&Locale::German, "MISSING", &args), None);
+    }
+
+    #[test]
+    fn test_plural() {
+        let r = resources();
+        assert_eq!(
+            r.get(&Locale::AmericanEnglish, "ITEMS", &[("count", "1")]),
+            Some(String::from("1 item"))
+        );
+        assert_eq!(
+            r.get(&Locale::AmericanEnglish, "ITEMS", &[("count", "0")]),
+            Some(String::from("0 items"))
+        );
+        assert_eq!(
+            r.get(&Locale::French, "ITEMS", &[("count", "0")]),
+            Some(String::from("0 article"))
+        );
+        assert_eq!(
+            PluralCategory::for_count(&Locale::Arabic, 105),
+            PluralCategory::Few
+        );
+    }
+
+    #[test]
+    fn test_variants() {
+        let r = resources();
+        let s = r.get(&Locale::AmericanEnglish, "BYE", &[]).unwrap();
+        assert!(s == "Bye" || s == "See you");
+    }
+}
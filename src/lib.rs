@@ -7,8 +7,17 @@
 //! 
 //! Simplest possible Alexa "Hello, World" skill:
 //!
-//! ```rust
-//! extern crate lambda_runtime as lambda;
+//! ```rust,no_run
+//! # mod lambda {
+//! #     pub struct Context;
+//! #     pub mod error {
+//! #         #[derive(Debug)]
+//! #         pub struct HandlerError;
+//! #     }
+//! #     macro_rules! lambda { ($h:ident) => { let _ = $h; }; }
+//! #     pub(crate) use lambda;
+//! # }
+//! // lambda_runtime, imported as `lambda`
 //! extern crate alexa_sdk;
 //!
 //! use lambda::{lambda, Context, error::HandlerError};
@@ -28,8 +37,17 @@
 //!
 //! A more complete skill, handling multiple locales and a slot:
 //!
-//! ```rust
-//! extern crate lambda_runtime as lambda;
+//! ```rust,no_run
+//! # mod lambda {
+//! #     pub struct Context;
+//! #     pub mod error {
+//! #         #[derive(Debug)]
+//! #         pub struct HandlerError;
+//! #     }
+//! #     macro_rules! lambda { ($h:ident) => { let _ = $h; }; }
+//! #     pub(crate) use lambda;
+//! # }
+//! // lambda_runtime, imported as `lambda`
 //! extern crate alexa_sdk;
 //!
 //! use lambda::{lambda, Context, error::HandlerError};
@@ -46,7 +64,7 @@
 //!         Locale::AustralianEnglish => Response::simple("hello", "G'day mate"),
 //!         Locale::German => Response::simple("hello", "Hallo Welt"),
 //!         Locale::Japanese => Response::simple("hello", "こんにちは世界"),
-//!         _ => if let Some(ref s) = req.slot_value("name") {
+//!         _ => if let Some(Some(ref s)) = req.slot_value("name") {
 //!             Response::simple("hello", (String::from("hello ") + s).as_str())
 //!         } else {
 //!             Response::simple("hello", "hello world")
@@ -74,8 +92,15 @@
 //! }
 //! ```
 
+pub mod i18n;
 pub mod request;
+pub mod request_builder;
 pub mod response;
+pub mod service;
+pub mod slot_types;
+pub mod snapshot;
+pub mod testing;
+mod util;
 
 pub use self::request::{Request};
 pub use self::response::{Response};
\ No newline at end of file
@@ -3,17 +3,49 @@ extern crate serde_derive;
 extern crate serde_json;
 
 use self::serde_derive::{Deserialize, Serialize};
+use crate::i18n::Resources;
+use std::cell::Cell;
 use std::collections::HashMap;
-use std::convert::From;
+use std::convert::{From, Infallible};
+use std::fmt;
+use std::str::FromStr;
+
+/// Fields not modelled by the request structs, keyed by JSON field name
+/// only populated with the `lossless` feature, which allows requests to be
+/// re-serialized without losing data; absent optional fields are then omitted
+/// rather than serialized as `null`
+pub type Extra = HashMap<String, self::serde_json::Value>;
+
+thread_local! {
+    /// set while `Request::from_str_strict` serializes the modelled fields, so that
+    /// absent fields are serialized too and can be told apart from unknown ones
+    static SERIALIZE_ABSENT: Cell<bool> = const { Cell::new(false) };
+}
+
+fn is_none<T>(v: &Option<T>) -> bool {
+    v.is_none() && !SERIALIZE_ABSENT.with(Cell::get)
+}
+
+fn is_empty<T>(v: &[T]) -> bool {
+    v.is_empty() && !SERIALIZE_ABSENT.with(Cell::get)
+}
+
+fn is_empty_str(s: &str) -> bool {
+    s.is_empty() && !SERIALIZE_ABSENT.with(Cell::get)
+}
 
 /// Request struct corresponding to the [Alexa spec](https://developer.amazon.com/docs/custom-skills/request-and-response-json-reference.html#request-body-parameters)
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Request {
     pub version: String,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     pub session: Option<Session>,
     #[serde(rename = "request")]
     pub body: ReqBody,
     pub context: Context,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -21,29 +53,43 @@ pub struct Session {
     pub new: bool,
     #[serde(rename = "sessionId")]
     pub session_id: String,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     pub attributes: Option<HashMap<String, String>>,
     pub application: Application,
     pub user: User,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Application {
     #[serde(rename = "applicationId")]
     pub application_id: String,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct User {
     #[serde(rename = "userId")]
     pub user_id: String,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     #[serde(rename = "accessToken")]
     pub access_token: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Device {
     #[serde(rename = "deviceId")]
     pub device_id: String,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -53,44 +99,253 @@ pub struct ReqBody {
     #[serde(rename = "requestId")]
     pub request_id: String,
     pub timestamp: String,
+    /// empty for skill events, which have no locale, and then not serialized
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_empty_str")]
     pub locale: String,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     pub intent: Option<Intent>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     pub reason: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     #[serde(rename = "dialogState")]
     pub dialog_state: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub error: Option<RequestError>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub cause: Option<Cause>,
+    #[serde(skip_serializing_if = "is_none")]
+    #[serde(rename = "body")]
+    pub event: Option<EventBody>,
+    /// the name of the request, e.g. `Buy`, for `Connections.Response`
+    #[serde(skip_serializing_if = "is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "is_none")]
+    pub status: Option<ConnectionStatus>,
+    #[serde(skip_serializing_if = "is_none")]
+    pub payload: Option<ConnectionPayload>,
+    /// the token sent with the `Connections.SendRequest` directive
+    #[serde(skip_serializing_if = "is_none")]
+    pub token: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// Error reported by Alexa on `System.ExceptionEncountered` and `SessionEndedRequest`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestError {
+    #[serde(rename = "type")]
+    pub error_type: String,
+    pub message: String,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// The body of a skill event, e.g. `Reminders.ReminderCreated`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EventBody {
+    #[serde(skip_serializing_if = "is_none")]
+    #[serde(rename = "alertToken")]
+    pub alert_token: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_empty")]
+    #[serde(rename = "alertTokens")]
+    pub alert_tokens: Vec<String>,
+    #[serde(skip_serializing_if = "is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "is_none")]
+    #[serde(rename = "listId")]
+    pub list_id: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_empty")]
+    #[serde(rename = "listItemIds")]
+    pub list_item_ids: Vec<String>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// The status of a `Connections.Response`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConnectionStatus {
+    /// HTTP-style status code, e.g. `200`
+    #[serde(skip_serializing_if = "is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "is_none")]
+    pub message: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// The result of an in-skill purchase, for `Connections.Response`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConnectionPayload {
+    #[serde(skip_serializing_if = "is_none")]
+    #[serde(rename = "purchaseResult")]
+    pub purchase_result: Option<String>,
+    #[serde(skip_serializing_if = "is_none")]
+    #[serde(rename = "productId")]
+    pub product_id: Option<String>,
+    #[serde(skip_serializing_if = "is_none")]
+    pub message: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// The request that caused a `System.ExceptionEncountered`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Cause {
+    #[serde(rename = "requestId")]
+    pub request_id: String,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Context {
     #[serde(rename = "System")]
     pub system: System,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     #[serde(rename = "AudioPlayer")]
     pub audio_player: Option<AudioPlayer>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "Viewport")]
+    pub viewport: Option<Viewport>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct System {
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     #[serde(rename = "apiAccessToken")]
     pub api_access_token: Option<String>,
+    #[serde(skip_serializing_if = "is_none")]
+    #[serde(rename = "apiEndpoint")]
+    pub api_endpoint: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     pub device: Option<Device>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     pub application: Option<Application>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub user: Option<User>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub person: Option<Person>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub unit: Option<Unit>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// The recognized speaker, when the skill supports personalization
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Person {
+    #[serde(rename = "personId")]
+    pub person_id: String,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "accessToken")]
+    pub access_token: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// The organizational unit (e.g. hotel room) of the device, for Alexa for Business
+/// and Alexa Smart Properties skills
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Unit {
+    #[serde(rename = "unitId")]
+    pub unit_id: String,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "persistentUnitId")]
+    pub persistent_unit_id: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// Characteristics of the device screen
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Viewport {
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub experiences: Option<Vec<Experience>>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub shape: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "pixelWidth")]
+    pub pixel_width: Option<u32>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "pixelHeight")]
+    pub pixel_height: Option<u32>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub dpi: Option<u32>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "currentPixelWidth")]
+    pub current_pixel_width: Option<u32>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "currentPixelHeight")]
+    pub current_pixel_height: Option<u32>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub touch: Option<Vec<String>>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub keyboard: Option<Vec<String>>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Experience {
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "arcMinuteWidth")]
+    pub arc_minute_width: Option<u32>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "arcMinuteHeight")]
+    pub arc_minute_height: Option<u32>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "canRotate")]
+    pub can_rotate: Option<bool>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "canResize")]
+    pub can_resize: Option<bool>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AudioPlayer {
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     pub token: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     #[serde(rename = "offsetInMilliseconds")]
     pub offset_in_milliseconds: Option<u64>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     #[serde(rename = "playerActivity")]
     pub player_activity: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Intent {
     pub name: String,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     #[serde(rename = "confirmationStatus")]
     pub confirmation_status: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     pub slots: Option<HashMap<String, Slot>>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl Intent {
@@ -102,39 +357,233 @@ impl Intent {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Slot {
     pub name: String,
-    pub value: String,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub value: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     #[serde(rename = "confirmationStatus")]
     pub confirmation_status: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub source: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
     pub resolutions: Option<Resolution>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    #[serde(rename = "slotValue")]
+    pub slot_value: Option<SlotValue>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// Value of a slot, either a single value or, for multiple-value slots, a list of values
+/// values of types this crate does not know are kept as raw JSON in `Other`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum SlotValue {
+    Simple(SimpleSlotValue),
+    List {
+        values: Vec<SlotValue>,
+        #[cfg_attr(feature = "lossless", serde(flatten))]
+        #[cfg_attr(not(feature = "lossless"), serde(skip))]
+        extra: Extra,
+    },
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// A single slot value, with its own entity resolutions
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SimpleSlotValue {
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub value: Option<String>,
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_none"))]
+    pub resolutions: Option<Resolution>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl SlotValue {
+    /// returns all simple values, flattening lists
+    pub fn values(&self) -> Vec<&SimpleSlotValue> {
+        match self {
+            SlotValue::Simple(v) => vec![v],
+            SlotValue::List { values, .. } => values.iter().flat_map(|v| v.values()).collect(),
+            SlotValue::Other(_) => Vec::new(),
+        }
+    }
+
+    fn clear_extra(&mut self) {
+        match self {
+            SlotValue::Simple(v) => {
+                v.extra.clear();
+                if let Some(ref mut r) = v.resolutions {
+                    r.clear_extra();
+                }
+            }
+            SlotValue::List { values, extra } => {
+                extra.clear();
+                values.iter_mut().for_each(SlotValue::clear_extra);
+            }
+            SlotValue::Other(_) => {}
+        }
+    }
+}
+
+impl Slot {
+    /// returns the values of the slot, using `slotValue` when present and of a known
+    /// type, and falling back to the top-level value and resolutions otherwise
+    pub fn values(&self) -> Vec<SimpleSlotValue> {
+        match self.slot_value {
+            Some(SlotValue::Other(_)) | None => match self.value {
+                Some(_) => vec![SimpleSlotValue {
+                    value: self.value.clone(),
+                    resolutions: self.resolutions.clone(),
+                    extra: Extra::new(),
+                }],
+                None => Vec::new(),
+            },
+            Some(ref sv) => sv.values().into_iter().cloned().collect(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Resolution {
     #[serde(rename = "resolutionsPerAuthority")]
     pub resolutions_per_authority: Vec<ResolutionsPerAuthority>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ResolutionsPerAuthority {
     pub authority: String,
     pub status: Status,
+    #[serde(default)]
+    #[cfg_attr(feature = "lossless", serde(skip_serializing_if = "is_empty"))]
     pub values: Vec<ValueWrapper>,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Status {
     pub code: String,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ValueWrapper {
     pub value: Value,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Value {
     pub name: String,
     pub id: String,
+    #[cfg_attr(feature = "lossless", serde(flatten))]
+    #[cfg_attr(not(feature = "lossless"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// Entity resolution status codes
+#[derive(Debug, PartialEq)]
+pub enum ResolutionStatusCode {
+    SuccessMatch,
+    SuccessNoMatch,
+    ErrorTimeout,
+    ErrorException,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for ResolutionStatusCode {
+    fn from(s: &'a str) -> ResolutionStatusCode {
+        match s {
+            "ER_SUCCESS_MATCH" => ResolutionStatusCode::SuccessMatch,
+            "ER_SUCCESS_NO_MATCH" => ResolutionStatusCode::SuccessNoMatch,
+            "ER_ERROR_TIMEOUT" => ResolutionStatusCode::ErrorTimeout,
+            "ER_ERROR_EXCEPTION" => ResolutionStatusCode::ErrorException,
+            _ => ResolutionStatusCode::Other(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for ResolutionStatusCode {
+    fn from(s: String) -> ResolutionStatusCode {
+        ResolutionStatusCode::from(s.as_str())
+    }
+}
+
+/// Kind of authority that resolved a slot value: the static values of the
+/// slot type in the interaction model, or dynamic entities set at runtime
+#[derive(Debug, PartialEq)]
+pub enum AuthorityType {
+    Static,
+    Dynamic,
+}
+
+impl<'a> From<&'a str> for AuthorityType {
+    fn from(s: &'a str) -> AuthorityType {
+        if s.starts_with("amzn1.er-authority.echo-sdk.dynamic.") {
+            AuthorityType::Dynamic
+        } else {
+            AuthorityType::Static
+        }
+    }
+}
+
+impl Status {
+    /// Extracts the typed status code
+    pub fn status_code(&self) -> ResolutionStatusCode {
+        ResolutionStatusCode::from(&*self.code)
+    }
+}
+
+impl ResolutionsPerAuthority {
+    /// returns true if this authority matched the slot value
+    pub fn is_match(&self) -> bool {
+        self.status.status_code() == ResolutionStatusCode::SuccessMatch
+    }
+
+    /// returns whether the authority holds static or dynamic entities
+    pub fn authority_type(&self) -> AuthorityType {
+        AuthorityType::from(&*self.authority)
+    }
+}
+
+impl Resolution {
+    /// returns the first authority with a successful match, if any
+    pub fn first_match(&self) -> Option<&ResolutionsPerAuthority> {
+        self.resolutions_per_authority.iter().find(|r| r.is_match())
+    }
+
+    /// returns all values matched by any authority, in order
+    pub fn matches(&self) -> Vec<&Value> {
+        self.resolutions_per_authority
+            .iter()
+            .filter(|r| r.is_match())
+            .flat_map(|r| r.values.iter().map(|v| &v.value))
+            .collect()
+    }
+
+    fn clear_extra(&mut self) {
+        self.extra.clear();
+        for r in self.resolutions_per_authority.iter_mut() {
+            r.extra.clear();
+            r.status.extra.clear();
+            for v in r.values.iter_mut() {
+                v.extra.clear();
+                v.value.extra.clear();
+            }
+        }
+    }
 }
 
 /// Enumeration of Alexa request types
@@ -145,6 +594,18 @@ pub enum ReqType {
     IntentRequest,
     SessionEndedRequest,
     CanFulfillIntentRequest,
+    SystemExceptionEncountered,
+    ReminderCreated,
+    ReminderUpdated,
+    ReminderDeleted,
+    ReminderStatusChanged,
+    ListCreated,
+    ListUpdated,
+    ListDeleted,
+    ListItemsCreated,
+    ListItemsUpdated,
+    ListItemsDeleted,
+    ConnectionsResponse,
     Other(String),
 }
 
@@ -155,6 +616,18 @@ impl<'a> From<&'a str> for ReqType {
             "IntentRequest" => ReqType::IntentRequest,
             "SessionEndedRequest" => ReqType::SessionEndedRequest,
             "CanFulfillIntentRequest" => ReqType::CanFulfillIntentRequest,
+            "System.ExceptionEncountered" => ReqType::SystemExceptionEncountered,
+            "Reminders.ReminderCreated" => ReqType::ReminderCreated,
+            "Reminders.ReminderUpdated" => ReqType::ReminderUpdated,
+            "Reminders.ReminderDeleted" => ReqType::ReminderDeleted,
+            "Reminders.ReminderStatusChanged" => ReqType::ReminderStatusChanged,
+            "AlexaHouseholdListEvent.ListCreated" => ReqType::ListCreated,
+            "AlexaHouseholdListEvent.ListUpdated" => ReqType::ListUpdated,
+            "AlexaHouseholdListEvent.ListDeleted" => ReqType::ListDeleted,
+            "AlexaHouseholdListEvent.ItemsCreated" => ReqType::ListItemsCreated,
+            "AlexaHouseholdListEvent.ItemsUpdated" => ReqType::ListItemsUpdated,
+            "AlexaHouseholdListEvent.ItemsDeleted" => ReqType::ListItemsDeleted,
+            "Connections.Response" => ReqType::ConnectionsResponse,
             _ => ReqType::Other(s.to_string()),
         }
     }
@@ -166,8 +639,161 @@ impl From<String> for ReqType {
     }
 }
 
+/// A `Reminders.*` event sent to the skill when one of its reminders changes
+#[derive(Debug, PartialEq)]
+pub enum ReminderEvent {
+    Created { alert_token: String },
+    Updated { alert_token: String },
+    Deleted { alert_tokens: Vec<String> },
+    StatusChanged { alert_token: String, status: String },
+}
+
+/// An `AlexaHouseholdListEvent.*` event sent to the skill when a household list changes
+#[derive(Debug, PartialEq)]
+pub enum ListEvent {
+    ListCreated {
+        list_id: String,
+    },
+    ListUpdated {
+        list_id: String,
+    },
+    ListDeleted {
+        list_id: String,
+    },
+    ItemsCreated {
+        list_id: String,
+        list_item_ids: Vec<String>,
+    },
+    ItemsUpdated {
+        list_id: String,
+        list_item_ids: Vec<String>,
+    },
+    ItemsDeleted {
+        list_id: String,
+        list_item_ids: Vec<String>,
+    },
+}
+
+/// Enumeration of in-skill purchase results
+#[derive(Debug, PartialEq)]
+pub enum PurchaseResult {
+    Accepted,
+    Declined,
+    AlreadyPurchased,
+    Error,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for PurchaseResult {
+    fn from(s: &'a str) -> PurchaseResult {
+        match s {
+            "ACCEPTED" => PurchaseResult::Accepted,
+            "DECLINED" => PurchaseResult::Declined,
+            "ALREADY_PURCHASED" => PurchaseResult::AlreadyPurchased,
+            "ERROR" => PurchaseResult::Error,
+            _ => PurchaseResult::Other(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for PurchaseResult {
+    fn from(s: String) -> PurchaseResult {
+        PurchaseResult::from(s.as_str())
+    }
+}
+
+/// Enumeration of error types reported by Alexa
+#[derive(Debug, PartialEq)]
+pub enum ErrorType {
+    InvalidResponse,
+    DeviceCommunicationError,
+    InternalServiceError,
+    InternalError,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for ErrorType {
+    fn from(s: &'a str) -> ErrorType {
+        match s {
+            "INVALID_RESPONSE" => ErrorType::InvalidResponse,
+            "DEVICE_COMMUNICATION_ERROR" => ErrorType::DeviceCommunicationError,
+            "INTERNAL_SERVICE_ERROR" => ErrorType::InternalServiceError,
+            "INTERNAL_ERROR" => ErrorType::InternalError,
+            _ => ErrorType::Other(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for ErrorType {
+    fn from(s: String) -> ErrorType {
+        ErrorType::from(s.as_str())
+    }
+}
+
+impl RequestError {
+    /// Extracts the error type
+    pub fn error_type(&self) -> ErrorType {
+        ErrorType::from(&*self.error_type)
+    }
+}
+
+/// Confirmation status of an intent or slot
+#[derive(Debug, PartialEq)]
+pub enum ConfirmationStatus {
+    None,
+    Confirmed,
+    Denied,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for ConfirmationStatus {
+    fn from(s: &'a str) -> ConfirmationStatus {
+        match s {
+            "NONE" => ConfirmationStatus::None,
+            "CONFIRMED" => ConfirmationStatus::Confirmed,
+            "DENIED" => ConfirmationStatus::Denied,
+            _ => ConfirmationStatus::Other(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for ConfirmationStatus {
+    fn from(s: String) -> ConfirmationStatus {
+        ConfirmationStatus::from(s.as_str())
+    }
+}
+
+/// State of a multi-turn dialog
+#[derive(Debug, PartialEq)]
+pub enum DialogState {
+    Started,
+    InProgress,
+    Completed,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for DialogState {
+    fn from(s: &'a str) -> DialogState {
+        match s {
+            "STARTED" => DialogState::Started,
+            "IN_PROGRESS" => DialogState::InProgress,
+            "COMPLETED" => DialogState::Completed,
+            _ => DialogState::Other(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for DialogState {
+    fn from(s: String) -> DialogState {
+        DialogState::from(s.as_str())
+    }
+}
+
 /// Enumeration of Alexa intent types
-/// Custom intents will be User enum values discrimiated by the `String` value
+/// Custom intents will be User enum values discrimiated by the `String` value,
+/// unrecognized built-in `AMAZON.` intents will be Builtin values, and
+/// action-style built-ins such as `AMAZON.SearchAction<object@WeatherForecast>`
+/// will be Action values
 #[derive(Debug, PartialEq)]
 pub enum IntentType {
     None,
@@ -176,25 +802,202 @@ pub enum IntentType {
     Fallback,
     LoopOff,
     LoopOn,
+    More,
     NavigateHome,
+    NavigateSettings,
     Next,
     No,
+    PageDown,
+    PageUp,
     Pause,
     Previous,
     Repeat,
     Resume,
+    ScrollDown,
+    ScrollLeft,
+    ScrollRight,
+    ScrollUp,
     Select,
+    SendToPhone,
     ShuffleOn,
     ShuffleOff,
     StartOver,
     Stop,
     Yes,
+    Action(ActionIntent),
+    Builtin(String),
     User(String),
 }
 
-/// Alexa standard locales
+impl<'a> From<&'a str> for IntentType {
+    fn from(s: &'a str) -> IntentType {
+        match s {
+            "" => IntentType::None,
+            "AMAZON.HelpIntent" => IntentType::Help,
+            "AMAZON.CancelIntent" => IntentType::Cancel,
+            "AMAZON.FallbackIntent" => IntentType::Fallback,
+            "AMAZON.LoopOffIntent" => IntentType::LoopOff,
+            "AMAZON.LoopOnIntent" => IntentType::LoopOn,
+            "AMAZON.MoreIntent" => IntentType::More,
+            "AMAZON.NavigateHomeIntent" => IntentType::NavigateHome,
+            "AMAZON.NavigateSettingsIntent" => IntentType::NavigateSettings,
+            "AMAZON.NextIntent" => IntentType::Next,
+            "AMAZON.NoIntent" => IntentType::No,
+            "AMAZON.PageDownIntent" => IntentType::PageDown,
+            "AMAZON.PageUpIntent" => IntentType::PageUp,
+            "AMAZON.PauseIntent" => IntentType::Pause,
+            "AMAZON.PreviousIntent" => IntentType::Previous,
+            "AMAZON.RepeatIntent" => IntentType::Repeat,
+            "AMAZON.ResumeIntent" => IntentType::Resume,
+            "AMAZON.ScrollDownIntent" => IntentType::ScrollDown,
+            "AMAZON.ScrollLeftIntent" => IntentType::ScrollLeft,
+            "AMAZON.ScrollRightIntent" => IntentType::ScrollRight,
+            "AMAZON.ScrollUpIntent" => IntentType::ScrollUp,
+            "AMAZON.SelectIntent" => IntentType::Select,
+            "AMAZON.SendToPhoneIntent" => IntentType::SendToPhone,
+            "AMAZON.ShuffleOffIntent" => IntentType::ShuffleOff,
+            "AMAZON.ShuffleOnIntent" => IntentType::ShuffleOn,
+            "AMAZON.StartOverIntent" => IntentType::StartOver,
+            "AMAZON.StopIntent" => IntentType::Stop,
+            "AMAZON.YesIntent" => IntentType::Yes,
+            _ if s.starts_with("AMAZON.") => match ActionIntent::parse(s) {
+                Some(a) => IntentType::Action(a),
+                None => IntentType::Builtin(s.to_string()),
+            },
+            _ => IntentType::User(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for IntentType {
+    fn from(s: String) -> IntentType {
+        IntentType::from(s.as_str())
+    }
+}
+
+impl FromStr for IntentType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<IntentType, Infallible> {
+        Ok(IntentType::from(s))
+    }
+}
+
+impl fmt::Display for IntentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            IntentType::None => "",
+            IntentType::Help => "AMAZON.HelpIntent",
+            IntentType::Cancel => "AMAZON.CancelIntent",
+            IntentType::Fallback => "AMAZON.FallbackIntent",
+            IntentType::LoopOff => "AMAZON.LoopOffIntent",
+            IntentType::LoopOn => "AMAZON.LoopOnIntent",
+            IntentType::More => "AMAZON.MoreIntent",
+            IntentType::NavigateHome => "AMAZON.NavigateHomeIntent",
+            IntentType::NavigateSettings => "AMAZON.NavigateSettingsIntent",
+            IntentType::Next => "AMAZON.NextIntent",
+            IntentType::No => "AMAZON.NoIntent",
+            IntentType::PageDown => "AMAZON.PageDownIntent",
+            IntentType::PageUp => "AMAZON.PageUpIntent",
+            IntentType::Pause => "AMAZON.PauseIntent",
+            IntentType::Previous => "AMAZON.PreviousIntent",
+            IntentType::Repeat => "AMAZON.RepeatIntent",
+            IntentType::Resume => "AMAZON.ResumeIntent",
+            IntentType::ScrollDown => "AMAZON.ScrollDownIntent",
+            IntentType::ScrollLeft => "AMAZON.ScrollLeftIntent",
+            IntentType::ScrollRight => "AMAZON.ScrollRightIntent",
+            IntentType::ScrollUp => "AMAZON.ScrollUpIntent",
+            IntentType::Select => "AMAZON.SelectIntent",
+            IntentType::SendToPhone => "AMAZON.SendToPhoneIntent",
+            IntentType::ShuffleOff => "AMAZON.ShuffleOffIntent",
+            IntentType::ShuffleOn => "AMAZON.ShuffleOnIntent",
+            IntentType::StartOver => "AMAZON.StartOverIntent",
+            IntentType::Stop => "AMAZON.StopIntent",
+            IntentType::Yes => "AMAZON.YesIntent",
+            IntentType::Action(ref a) => return write!(f, "{}", a),
+            IntentType::Builtin(ref s) | IntentType::User(ref s) => s,
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A built-in action intent, such as `AMAZON.SearchAction<object@WeatherForecast[temperature]>`
+#[derive(Debug, PartialEq)]
+pub struct ActionIntent {
+    /// the action, e.g. `SearchAction`
+    pub action: String,
+    /// the entities the action applies to
+    pub entities: Vec<ActionEntity>,
+}
+
+/// An entity of an action intent, such as `object@WeatherForecast[temperature]`
 #[derive(Debug, PartialEq)]
+pub struct ActionEntity {
+    /// the role of the entity in the action, e.g. `object` or `targetCollection`
+    pub role: String,
+    /// the entity type, e.g. `WeatherForecast`
+    pub entity_type: String,
+    /// the property of the entity, e.g. `temperature`
+    pub property: Option<String>,
+}
+
+impl ActionIntent {
+    fn parse(s: &str) -> Option<ActionIntent> {
+        let name = s.strip_prefix("AMAZON.")?;
+        let open = name.find('<')?;
+        let inner = name[open + 1..].strip_suffix('>')?;
+        let entities = inner
+            .split(',')
+            .map(ActionEntity::parse)
+            .collect::<Option<Vec<ActionEntity>>>()?;
+        Some(ActionIntent {
+            action: name[..open].to_string(),
+            entities,
+        })
+    }
+}
+
+impl ActionEntity {
+    fn parse(s: &str) -> Option<ActionEntity> {
+        let at = s.find('@')?;
+        let rest = &s[at + 1..];
+        let (entity_type, property) = match rest.find('[') {
+            Some(b) => (
+                &rest[..b],
+                Some(rest[b + 1..].strip_suffix(']')?.to_string()),
+            ),
+            None => (rest, None),
+        };
+        Some(ActionEntity {
+            role: s[..at].to_string(),
+            entity_type: entity_type.to_string(),
+            property,
+        })
+    }
+}
+
+impl fmt::Display for ActionIntent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let entities: Vec<String> = self.entities.iter().map(|e| e.to_string()).collect();
+        write!(f, "AMAZON.{}<{}>", self.action, entities.join(","))
+    }
+}
+
+impl fmt::Display for ActionEntity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}@{}", self.role, self.entity_type)?;
+        if let Some(ref p) = self.property {
+            write!(f, "[{}]", p)?;
+        }
+        Ok(())
+    }
+}
+
+/// Alexa standard locales
+/// Locales not supported by Alexa are put into the Unknown `String` value
+#[derive(Debug, PartialEq, Clone)]
 pub enum Locale {
+    Arabic,
     Italian,
     German,
     AustralianEnglish,
@@ -209,42 +1012,83 @@ pub enum Locale {
     Hindi,
     French,
     CanadianFrench,
+    Dutch,
     BrazilianPortuguese,
-    Unknown,
+    Portuguese,
+    Unknown(String),
 }
 
 impl Locale {
-    /// returns true for all English speaking locals
-    pub fn is_english(&self) -> bool {
+    /// returns the BCP-47 language tag, e.g. `fr-CA`
+    pub fn tag(&self) -> &str {
         match *self {
-            Locale::AmericanEnglish => true,
-            Locale::AustralianEnglish => true,
-            Locale::CanadianEnglish => true,
-            Locale::BritishEnglish => true,
-            Locale::IndianEnglish => true,
-            _ => false,
+            Locale::Arabic => "ar-SA",
+            Locale::Italian => "it-IT",
+            Locale::German => "de-DE",
+            Locale::AustralianEnglish => "en-AU",
+            Locale::CanadianEnglish => "en-CA",
+            Locale::BritishEnglish => "en-GB",
+            Locale::IndianEnglish => "en-IN",
+            Locale::AmericanEnglish => "en-US",
+            Locale::Japanese => "ja-JP",
+            Locale::Spanish => "es-ES",
+            Locale::MexicanSpanish => "es-MX",
+            Locale::AmericanSpanish => "es-US",
+            Locale::Hindi => "hi-IN",
+            Locale::French => "fr-FR",
+            Locale::CanadianFrench => "fr-CA",
+            Locale::Dutch => "nl-NL",
+            Locale::BrazilianPortuguese => "pt-BR",
+            Locale::Portuguese => "pt-PT",
+            Locale::Unknown(ref s) => s,
         }
     }
-    pub fn is_french(&self) -> bool {
-        match *self {
-            Locale::French => true,
-            Locale::CanadianFrench => true,
-            _ => false,
+
+    /// returns the language subtag, e.g. `fr` for `fr-CA`
+    pub fn language(&self) -> &str {
+        let tag = self.tag();
+        match tag.find('-') {
+            Some(i) => &tag[..i],
+            None => tag,
         }
     }
-    pub fn is_spanish(&self) -> bool {
-        match *self {
-            Locale::Spanish => true,
-            Locale::AmericanSpanish => true,
-            Locale::MexicanSpanish => true,
-            _ => false,
+
+    /// returns the region subtag, e.g. `CA` for `fr-CA`, if there is one
+    pub fn region(&self) -> Option<&str> {
+        let tag = self.tag();
+        Some(&tag[tag.find('-')? + 1..])
+    }
+
+    /// returns the tags to try, most specific first, when looking up localized
+    /// resources, e.g. `["fr-CA", "fr"]` for `fr-CA`
+    /// callers should fall back to their default resources after the last entry
+    pub fn fallback_chain(&self) -> Vec<&str> {
+        let mut chain = vec![self.tag()];
+        if self.region().is_some() {
+            chain.push(self.language());
         }
+        chain
+    }
+
+    /// returns true for all English speaking locals
+    pub fn is_english(&self) -> bool {
+        self.language() == "en"
+    }
+    pub fn is_french(&self) -> bool {
+        self.language() == "fr"
+    }
+    pub fn is_spanish(&self) -> bool {
+        self.language() == "es"
+    }
+    pub fn is_portuguese(&self) -> bool {
+        self.language() == "pt"
     }
 }
 
 impl<'a> From<&'a str> for Locale {
     fn from(s: &'a str) -> Locale {
         match s {
+            "ar-SA" => Locale::Arabic,
             "it-IT" => Locale::Italian,
             "de-DE" => Locale::German,
             "en-AU" => Locale::AustralianEnglish,
@@ -253,14 +1097,16 @@ impl<'a> From<&'a str> for Locale {
             "en-IN" => Locale::IndianEnglish,
             "en-US" => Locale::AmericanEnglish,
             "ja-JP" => Locale::Japanese,
-            "hi-HI" => Locale::Hindi,
+            "hi-IN" => Locale::Hindi,
             "es-ES" => Locale::Spanish,
             "es-MX" => Locale::MexicanSpanish,
             "es-US" => Locale::AmericanSpanish,
             "fr-FR" => Locale::French,
             "fr-CA" => Locale::CanadianFrench,
+            "nl-NL" => Locale::Dutch,
             "pt-BR" => Locale::BrazilianPortuguese,
-            _ => Locale::Unknown,
+            "pt-PT" => Locale::Portuguese,
+            _ => Locale::Unknown(s.to_string()),
         }
     }
 }
@@ -271,6 +1117,20 @@ impl From<String> for Locale {
     }
 }
 
+impl FromStr for Locale {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Locale, Infallible> {
+        Ok(Locale::from(s))
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.tag())
+    }
+}
+
 impl Request {
     /// Extracts the request type from the request
     pub fn reqtype(&self) -> ReqType {
@@ -284,35 +1144,16 @@ impl Request {
 
     /// Extracts the intent from the request
     pub fn intent(&self) -> IntentType {
-        if let Some(ref i) = self.body.intent {
-            match i.name.as_str() {
-                "AMAZON.HelpIntent" => IntentType::Help,
-                "AMAZON.CancelIntent" => IntentType::Cancel,
-                "AMAZON.FallbackIntent" => IntentType::Fallback,
-                "AMAZON.LoopOffIntent" => IntentType::LoopOff,
-                "AMAZON.LoopOnIntent" => IntentType::LoopOn,
-                "AMAZON.NavigateHomeIntent" => IntentType::NavigateHome,
-                "AMAZON.NextIntent" => IntentType::Next,
-                "AMAZON.NoIntent" => IntentType::No,
-                "AMAZON.PauseIntent" => IntentType::Pause,
-                "AMAZON.PreviousIntent" => IntentType::Previous,
-                "AMAZON.RepeatIntent" => IntentType::Repeat,
-                "AMAZON.ResumeIntent" => IntentType::Resume,
-                "AMAZON.SelectIntent" => IntentType::Select,
-                "AMAZON.ShuffleOffIntent" => IntentType::ShuffleOff,
-                "AMAZON.ShuffleOnIntent" => IntentType::ShuffleOn,
-                "AMAZON.StartOverIntent" => IntentType::StartOver,
-                "AMAZON.StopIntent" => IntentType::Stop,
-                "AMAZON.YesIntent" => IntentType::Yes,
-                _ => IntentType::User(i.name.clone()),
-            }
-        } else {
-            IntentType::None
+        match self.body.intent {
+            Some(ref i) => IntentType::from(i.name.as_str()),
+            None => IntentType::None,
         }
     }
 
-    /// retrieves the string value of named slot from the request, if it exists
-    pub fn slot_value(&self, slot: &str) -> Option<String> {
+    /// retrieves the string value of named slot from the request
+    /// returns `None` if the slot does not exist and `Some(None)` if the slot
+    /// exists but was not filled by the user
+    pub fn slot_value(&self, slot: &str) -> Option<Option<String>> {
         Some(
             self.body
                 .intent
@@ -324,58 +1165,360 @@ impl Request {
         )
     }
 
-    /// retrieves the attribute value with the given key, if it exists
-    pub fn attribute_value(&self, key: &str) -> Option<&String> {
-        self.session.as_ref()?.attributes.as_ref()?.get(key)
+    /// retrieves the value of the named slot converted to a typed value, e.g. one of the
+    /// built-in slot types in `slot_types`
+    /// returns `None` if the slot does not exist, was not filled, or could not be parsed
+    pub fn slot_as<T: FromStr>(&self, slot: &str) -> Option<T> {
+        self.slot_value(slot)??.parse().ok()
     }
 
-    /// returns whether or not this is a new request
-    pub fn is_new(&self) -> bool {
-        match &self.session {
-            Some(s) => s.new,
-            None => false,
+    /// retrieves all values of the named slot, for both single- and multiple-value slots
+    /// returns an empty `Vec` if the slot does not exist or was not filled
+    pub fn slot_values(&self, slot: &str) -> Vec<SimpleSlotValue> {
+        match self.body.intent.as_ref().and_then(|i| i.get_slot(slot)) {
+            Some(s) => s.values(),
+            None => Vec::new(),
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Extracts the confirmation status of the intent, if there is an intent
+    pub fn intent_confirmation(&self) -> Option<ConfirmationStatus> {
+        let i = self.body.intent.as_ref()?;
+        Some(ConfirmationStatus::from(
+            i.confirmation_status.as_ref()?.as_str(),
+        ))
+    }
 
-    #[test]
-    fn test_version() {
-        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(default_req());
-        match p {
-            Ok(req) => assert_eq!(req.version, "1.0"),
-            Err(e) => panic!(e.to_string()),
-        }
+    /// returns true if the user has confirmed the intent
+    pub fn intent_confirmed(&self) -> bool {
+        self.intent_confirmation() == Some(ConfirmationStatus::Confirmed)
     }
 
-    #[test]
-    fn test_locale() {
-        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(default_req());
-        match p {
-            Ok(req) => assert_eq!(req.locale(), Locale::AmericanEnglish),
-            Err(e) => panic!(e.to_string()),
-        }
+    /// Extracts the confirmation status of the named slot, if it exists
+    pub fn slot_confirmation(&self, slot: &str) -> Option<ConfirmationStatus> {
+        let s = self.body.intent.as_ref()?.get_slot(slot)?;
+        Some(ConfirmationStatus::from(
+            s.confirmation_status.as_ref()?.as_str(),
+        ))
     }
 
-    #[test]
-    fn test_is_english() {
-        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(default_req());
-        match p {
-            Ok(req) => assert!(req.locale().is_english()),
-            Err(e) => panic!(e.to_string()),
-        }
+    /// Extracts the dialog state, if the request is part of a dialog
+    pub fn dialog_state(&self) -> Option<DialogState> {
+        Some(DialogState::from(self.body.dialog_state.as_ref()?.as_str()))
     }
 
-    #[test]
-    fn test_is_spanish() {
-        let p: Result<Request, serde_json::Error> =
-            self::serde_json::from_str(default_spanish_req());
-        match p {
-            Ok(req) => assert!(req.locale().is_spanish()),
-            Err(e) => panic!(e.to_string()),
+    fn slot_resolution(&self, slot: &str) -> Option<&Resolution> {
+        self.body
+            .intent
+            .as_ref()?
+            .get_slot(slot)?
+            .resolutions
+            .as_ref()
+    }
+
+    /// retrieves the first entity resolution match for the named slot, if any
+    pub fn slot_resolved(&self, slot: &str) -> Option<&Value> {
+        let r = self.slot_resolution(slot)?.first_match()?;
+        Some(&r.values.first()?.value)
+    }
+
+    /// retrieves the canonical value resolved for the named slot, if any
+    pub fn slot_resolved_value(&self, slot: &str) -> Option<String> {
+        Some(self.slot_resolved(slot)?.name.clone())
+    }
+
+    /// retrieves the id of the value resolved for the named slot, if any
+    pub fn slot_resolved_id(&self, slot: &str) -> Option<String> {
+        Some(self.slot_resolved(slot)?.id.clone())
+    }
+
+    /// retrieves all entity resolution matches for the named slot
+    pub fn slot_resolved_matches(&self, slot: &str) -> Vec<&Value> {
+        match self.slot_resolution(slot) {
+            Some(r) => r.matches(),
+            None => Vec::new(),
+        }
+    }
+
+    /// returns true if entity resolution matched more than one value for the named slot
+    pub fn slot_is_ambiguous(&self, slot: &str) -> bool {
+        self.slot_resolved_matches(slot).len() > 1
+    }
+
+    /// retrieves the type of authority that resolved the named slot, if any
+    pub fn slot_resolution_authority(&self, slot: &str) -> Option<AuthorityType> {
+        Some(self.slot_resolution(slot)?.first_match()?.authority_type())
+    }
+
+    /// retrieves the localized string for a key in the request locale from the
+    /// installed `i18n::Resources`, replacing `{name}` placeholders with args
+    /// returns the key itself if it cannot be found, or no resources are installed
+    pub fn t(&self, key: &str, args: &[(&str, &str)]) -> String {
+        match Resources::installed() {
+            Some(r) => self.t_with(r, key, args),
+            None => String::from(key),
+        }
+    }
+
+    /// retrieves the localized string for a key in the request locale from the
+    /// given resources, replacing `{name}` placeholders with args
+    /// returns the key itself if it cannot be found
+    pub fn t_with(&self, resources: &Resources, key: &str, args: &[(&str, &str)]) -> String {
+        resources
+            .get(&self.locale(), key, args)
+            .unwrap_or_else(|| String::from(key))
+    }
+
+    /// retrieves the attribute value with the given key, if it exists
+    pub fn attribute_value(&self, key: &str) -> Option<&String> {
+        self.session.as_ref()?.attributes.as_ref()?.get(key)
+    }
+
+    /// retrieves the error reported by Alexa, if any
+    pub fn error(&self) -> Option<&RequestError> {
+        self.body.error.as_ref()
+    }
+
+    /// retrieves the ID of the request that caused a `System.ExceptionEncountered`, if any
+    pub fn cause_request_id(&self) -> Option<&str> {
+        Some(self.body.cause.as_ref()?.request_id.as_str())
+    }
+
+    /// converts the timestamp of the request to local time in the given time zone,
+    /// e.g. `America/Los_Angeles` as returned by the Settings API
+    /// returns None if the timestamp or time zone are invalid
+    #[cfg(feature = "tz")]
+    pub fn local_time(&self, time_zone: &str) -> Option<chrono::DateTime<chrono_tz::Tz>> {
+        let tz: chrono_tz::Tz = time_zone.parse().ok()?;
+        let utc = chrono::DateTime::parse_from_rfc3339(&self.body.timestamp).ok()?;
+        Some(utc.with_timezone(&tz))
+    }
+
+    /// retrieves the name of the request, e.g. `Buy`, for `Connections.Response`
+    pub fn connection_name(&self) -> Option<&str> {
+        self.body.name.as_deref()
+    }
+
+    /// retrieves the token sent with the request, for `Connections.Response`
+    pub fn connection_token(&self) -> Option<&str> {
+        self.body.token.as_deref()
+    }
+
+    /// retrieves the result of an in-skill purchase, for `Connections.Response`
+    pub fn purchase_result(&self) -> Option<PurchaseResult> {
+        let result = self.body.payload.as_ref()?.purchase_result.as_ref()?;
+        Some(PurchaseResult::from(result.as_str()))
+    }
+
+    /// retrieves the ID of the product of an in-skill purchase, for `Connections.Response`
+    pub fn purchase_product_id(&self) -> Option<&str> {
+        self.body.payload.as_ref()?.product_id.as_deref()
+    }
+
+    /// retrieves the reminder event, for `Reminders.*` requests
+    pub fn reminder_event(&self) -> Option<ReminderEvent> {
+        let event = self.body.event.as_ref()?;
+        let token = || event.alert_token.clone().unwrap_or_default();
+        match self.reqtype() {
+            ReqType::ReminderCreated => Some(ReminderEvent::Created {
+                alert_token: token(),
+            }),
+            ReqType::ReminderUpdated => Some(ReminderEvent::Updated {
+                alert_token: token(),
+            }),
+            ReqType::ReminderDeleted => Some(ReminderEvent::Deleted {
+                alert_tokens: event.alert_tokens.clone(),
+            }),
+            ReqType::ReminderStatusChanged => Some(ReminderEvent::StatusChanged {
+                alert_token: token(),
+                status: event.status.clone().unwrap_or_default(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// retrieves the list event, for `AlexaHouseholdListEvent.*` requests
+    pub fn list_event(&self) -> Option<ListEvent> {
+        let event = self.body.event.as_ref()?;
+        let list_id = event.list_id.clone().unwrap_or_default();
+        let list_item_ids = event.list_item_ids.clone();
+        match self.reqtype() {
+            ReqType::ListCreated => Some(ListEvent::ListCreated { list_id }),
+            ReqType::ListUpdated => Some(ListEvent::ListUpdated { list_id }),
+            ReqType::ListDeleted => Some(ListEvent::ListDeleted { list_id }),
+            ReqType::ListItemsCreated => Some(ListEvent::ItemsCreated {
+                list_id,
+                list_item_ids,
+            }),
+            ReqType::ListItemsUpdated => Some(ListEvent::ItemsUpdated {
+                list_id,
+                list_item_ids,
+            }),
+            ReqType::ListItemsDeleted => Some(ListEvent::ItemsDeleted {
+                list_id,
+                list_item_ids,
+            }),
+            _ => None,
+        }
+    }
+
+    /// returns whether or not this is a new request
+    pub fn is_new(&self) -> bool {
+        match &self.session {
+            Some(s) => s.new,
+            None => false,
+        }
+    }
+
+    /// parses a request, also returning the JSON pointer path of every field
+    /// that is not modelled by the request structs, e.g. `/context/Display`
+    /// unknown fields do not cause parsing to fail, so recorded traffic can be run
+    /// through this to track coverage of the Alexa specification
+    pub fn from_str_strict(json: &str) -> Result<(Request, Vec<String>), serde_json::Error> {
+        let input: serde_json::Value = serde_json::from_str(json)?;
+        let req: Request = serde_json::from_value(input.clone())?;
+        SERIALIZE_ABSENT.with(|s| s.set(true));
+        let modelled = serde_json::to_value(req.clone().without_extra());
+        SERIALIZE_ABSENT.with(|s| s.set(false));
+        let modelled = modelled?;
+        let mut unknown = Vec::new();
+        unknown_fields("", &input, &modelled, &mut unknown);
+        Ok((req, unknown))
+    }
+
+    /// returns the request with all unmodelled fields removed
+    fn without_extra(mut self) -> Request {
+        self.extra.clear();
+        if let Some(ref mut s) = self.session {
+            s.extra.clear();
+            s.application.extra.clear();
+            s.user.extra.clear();
+        }
+        let body = &mut self.body;
+        body.extra.clear();
+        if let Some(ref mut i) = body.intent {
+            i.extra.clear();
+            for slot in i.slots.iter_mut().flat_map(|s| s.values_mut()) {
+                slot.extra.clear();
+                if let Some(ref mut r) = slot.resolutions {
+                    r.clear_extra();
+                }
+                if let Some(ref mut v) = slot.slot_value {
+                    v.clear_extra();
+                }
+            }
+        }
+        if let Some(ref mut e) = body.error {
+            e.extra.clear();
+        }
+        if let Some(ref mut c) = body.cause {
+            c.extra.clear();
+        }
+        if let Some(ref mut e) = body.event {
+            e.extra.clear();
+        }
+        if let Some(ref mut s) = body.status {
+            s.extra.clear();
+        }
+        if let Some(ref mut p) = body.payload {
+            p.extra.clear();
+        }
+        self.context.extra.clear();
+        let system = &mut self.context.system;
+        system.extra.clear();
+        if let Some(ref mut d) = system.device {
+            d.extra.clear();
+        }
+        if let Some(ref mut a) = system.application {
+            a.extra.clear();
+        }
+        if let Some(ref mut u) = system.user {
+            u.extra.clear();
+        }
+        if let Some(ref mut p) = system.person {
+            p.extra.clear();
+        }
+        if let Some(ref mut u) = system.unit {
+            u.extra.clear();
+        }
+        if let Some(ref mut a) = self.context.audio_player {
+            a.extra.clear();
+        }
+        if let Some(ref mut v) = self.context.viewport {
+            v.extra.clear();
+            for e in v.experiences.iter_mut().flatten() {
+                e.extra.clear();
+            }
+        }
+        self
+    }
+}
+
+/// collects the paths of fields in the input JSON that are not in the modelled JSON
+fn unknown_fields(
+    path: &str,
+    input: &serde_json::Value,
+    modelled: &serde_json::Value,
+    unknown: &mut Vec<String>,
+) {
+    use self::serde_json::Value as Json;
+    match (input, modelled) {
+        (Json::Object(i), Json::Object(m)) => {
+            for (key, value) in i {
+                let p = format!("{}/{}", path, key);
+                match m.get(key) {
+                    Some(mv) => unknown_fields(&p, value, mv, unknown),
+                    None => unknown.push(p),
+                }
+            }
+        }
+        (Json::Array(i), Json::Array(m)) => {
+            for (n, (iv, mv)) in i.iter().zip(m).enumerate() {
+                unknown_fields(&format!("{}/{}", path, n), iv, mv, unknown);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version() {
+        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(default_req());
+        match p {
+            Ok(req) => assert_eq!(req.version, "1.0"),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_locale() {
+        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(default_req());
+        match p {
+            Ok(req) => assert_eq!(req.locale(), Locale::AmericanEnglish),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_is_english() {
+        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(default_req());
+        match p {
+            Ok(req) => assert!(req.locale().is_english()),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_is_spanish() {
+        let p: Result<Request, serde_json::Error> =
+            self::serde_json::from_str(default_spanish_req());
+        match p {
+            Ok(req) => assert!(req.locale().is_spanish()),
+            Err(e) => panic!("{}", e),
         }
     }
 
@@ -385,15 +1528,59 @@ mod tests {
             self::serde_json::from_str(default_french_req());
         match p {
             Ok(req) => assert!(req.locale().is_french()),
-            Err(e) => panic!(e.to_string()),
+            Err(e) => panic!("{}", e),
         }
     }
+    #[test]
+    fn test_locale_tags() {
+        assert_eq!(Locale::from("hi-IN"), Locale::Hindi);
+        assert_eq!(Locale::from("ar-SA").to_string(), "ar-SA");
+        let l: Locale = "fr-CA".parse().unwrap();
+        assert_eq!(l.language(), "fr");
+        assert_eq!(l.region(), Some("CA"));
+        assert_eq!(l.fallback_chain(), vec!["fr-CA", "fr"]);
+        let u = Locale::from("sv-SE");
+        assert_eq!(u, Locale::Unknown(String::from("sv-SE")));
+        assert_eq!(u.to_string(), "sv-SE");
+        assert_eq!(u.language(), "sv");
+        assert_eq!(Locale::from("sv").fallback_chain(), vec!["sv"]);
+    }
+
     #[test]
     fn test_intent() {
         let p: Result<Request, serde_json::Error> = self::serde_json::from_str(default_req());
         match p {
             Ok(req) => assert_eq!(req.intent(), IntentType::User(String::from("hello"))),
-            Err(e) => panic!(e.to_string()),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_intent_names() {
+        assert_eq!(IntentType::from("AMAZON.PageUpIntent"), IntentType::PageUp);
+        assert_eq!(
+            IntentType::from("AMAZON.SomeNewIntent"),
+            IntentType::Builtin(String::from("AMAZON.SomeNewIntent"))
+        );
+        let names = [
+            "AMAZON.ScrollDownIntent",
+            "AMAZON.SomeNewIntent",
+            "AMAZON.SearchAction<object@WeatherForecast[temperature]>",
+            "AMAZON.AddAction<object@Book,targetCollection@ReadingList>",
+            "hello",
+        ];
+        for name in names.iter() {
+            let i: IntentType = name.parse().unwrap();
+            assert_eq!(i.to_string(), *name);
+        }
+        match IntentType::from("AMAZON.SearchAction<object@WeatherForecast[temperature]>") {
+            IntentType::Action(a) => {
+                assert_eq!(a.action, "SearchAction");
+                assert_eq!(a.entities[0].role, "object");
+                assert_eq!(a.entities[0].entity_type, "WeatherForecast");
+                assert_eq!(a.entities[0].property, Some(String::from("temperature")));
+            }
+            i => panic!("unexpected intent {:?}", i),
         }
     }
 
@@ -401,11 +1588,242 @@ mod tests {
     fn test_slot() {
         let p: Result<Request, serde_json::Error> = self::serde_json::from_str(req_with_slots());
         match p {
-            Ok(req) => assert_eq!(req.slot_value("name"), Some(String::from("bob"))),
-            Err(e) => panic!(e.to_string()),
+            Ok(req) => {
+                assert_eq!(req.slot_value("name"), Some(Some(String::from("bob"))));
+                assert_eq!(req.slot_value("missing"), None);
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_empty_slot() {
+        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(req_in_dialog());
+        match p {
+            Ok(req) => {
+                assert_eq!(req.slot_value("greeting"), Some(None));
+                let slot = req.body.intent.as_ref().unwrap().get_slot("name").unwrap();
+                assert_eq!(slot.source, Some(String::from("USER")));
+                assert!(slot.slot_value.is_some());
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_unknown_slot_value() {
+        let p: Result<Request, serde_json::Error> =
+            self::serde_json::from_str(req_with_unknown_slot_value());
+        match p {
+            Ok(req) => {
+                assert_eq!(req.slot_value("route"), Some(Some(String::from("home"))));
+                let slot = req.body.intent.as_ref().unwrap().get_slot("route").unwrap();
+                match slot.slot_value {
+                    Some(SlotValue::Other(ref v)) => assert_eq!(v["type"], "Composite"),
+                    ref v => panic!("expected an unknown slot value, got {:?}", v),
+                }
+                let values: Vec<Option<String>> = req
+                    .slot_values("route")
+                    .into_iter()
+                    .map(|v| v.value)
+                    .collect();
+                assert_eq!(values, vec![Some(String::from("home"))]);
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_slot_as() {
+        use crate::slot_types::AmazonNumber;
+        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(req_in_dialog());
+        match p {
+            Ok(req) => {
+                assert_eq!(req.slot_as::<String>("name"), Some(String::from("bob")));
+                assert_eq!(req.slot_as::<AmazonNumber>("name"), None);
+                assert_eq!(req.slot_as::<AmazonNumber>("greeting"), None);
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_slot_values() {
+        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(req_in_dialog());
+        match p {
+            Ok(req) => {
+                let names: Vec<Option<String>> = req
+                    .slot_values("name")
+                    .into_iter()
+                    .map(|v| v.value)
+                    .collect();
+                assert_eq!(names, vec![Some(String::from("bob"))]);
+                let toppings = req.slot_values("toppings");
+                assert_eq!(toppings.len(), 2);
+                assert_eq!(toppings[0].value, Some(String::from("cheese")));
+                assert_eq!(toppings[1].value, Some(String::from("olives")));
+                let r = toppings[1].resolutions.as_ref().unwrap();
+                assert_eq!(r.first_match().unwrap().values[0].value.id, "OLIVE");
+                assert!(req.slot_values("greeting").is_empty());
+                assert!(req.slot_values("missing").is_empty());
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_confirmation() {
+        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(req_in_dialog());
+        match p {
+            Ok(req) => {
+                assert_eq!(req.intent_confirmation(), Some(ConfirmationStatus::None));
+                assert!(!req.intent_confirmed());
+                assert_eq!(
+                    req.slot_confirmation("name"),
+                    Some(ConfirmationStatus::None)
+                );
+                assert_eq!(req.slot_confirmation("missing"), None);
+                assert_eq!(req.dialog_state(), Some(DialogState::InProgress));
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_resolution() {
+        let p: Result<Request, serde_json::Error> =
+            self::serde_json::from_str(req_with_resolutions());
+        match p {
+            Ok(req) => {
+                assert_eq!(req.slot_resolved_value("color"), Some(String::from("red")));
+                assert_eq!(req.slot_resolved_id("color"), Some(String::from("RED")));
+                assert_eq!(
+                    req.slot_resolution_authority("color"),
+                    Some(AuthorityType::Dynamic)
+                );
+                assert_eq!(req.slot_resolved_matches("color").len(), 2);
+                assert!(req.slot_is_ambiguous("color"));
+                assert_eq!(req.slot_resolved_value("size"), None);
+                assert!(!req.slot_is_ambiguous("size"));
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_viewport() {
+        let p: Result<Request, serde_json::Error> = self::serde_json::from_str(default_req());
+        match p {
+            Ok(req) => {
+                let v = req.context.viewport.unwrap();
+                assert_eq!(v.shape, Some(String::from("RECTANGLE")));
+                assert_eq!(v.pixel_width, Some(1024));
+                assert_eq!(v.experiences.unwrap()[0].can_rotate, Some(false));
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn resources() -> Resources {
+        Resources::new()
+            .add_json("en-US", r#"{"HELLO": "Hello {name}", "BYE": "Goodbye"}"#)
+            .unwrap()
+            .add_json("fr", r#"{"HELLO": "Bonjour {name}"}"#)
+            .unwrap()
+            .default_locale("en-US")
+    }
+
+    #[test]
+    fn test_t_with() {
+        let en: Request = self::serde_json::from_str(default_req()).unwrap();
+        let fr: Request = self::serde_json::from_str(default_french_req()).unwrap();
+        let r = resources();
+        assert_eq!(en.t_with(&r, "HELLO", &[("name", "Bob")]), "Hello Bob");
+        assert_eq!(fr.t_with(&r, "HELLO", &[("name", "Bob")]), "Bonjour Bob");
+        assert_eq!(fr.t_with(&r, "BYE", &[]), "Goodbye");
+        assert_eq!(fr.t_with(&r, "MISSING", &[]), "MISSING");
+    }
+
+    // the only test that installs resources, as they are global and can only be installed once
+    #[test]
+    fn test_t() {
+        let fr: Request = self::serde_json::from_str(default_french_req()).unwrap();
+        assert!(resources().install());
+        assert!(!Resources::new().install());
+        assert_eq!(fr.t("HELLO", &[("name", "Bob")]), "Bonjour Bob");
+        assert_eq!(fr.t("BYE", &[]), "Goodbye");
+        assert_eq!(fr.t("MISSING", &[]), "MISSING");
+    }
+
+    #[cfg(not(feature = "lossless"))]
+    #[test]
+    fn test_serialize_absent_fields() {
+        let req: Request = self::serde_json::from_str(default_req()).unwrap();
+        let v = self::serde_json::to_value(&req).unwrap();
+        assert_eq!(
+            v["request"].get("reason"),
+            Some(&self::serde_json::Value::Null)
+        );
+        assert_eq!(
+            v["context"].get("AudioPlayer"),
+            Some(&self::serde_json::Value::Null)
+        );
+    }
+
+    #[cfg(feature = "lossless")]
+    #[test]
+    fn test_round_trip() {
+        let reqs = [
+            default_req(),
+            default_spanish_req(),
+            default_french_req(),
+            req_with_slots(),
+            req_in_dialog(),
+            req_with_resolutions(),
+            req_with_unknown_slot_value(),
+            exception_encountered_req(),
+            reminder_event_req(),
+            list_event_req(),
+            connections_response_req(),
+        ];
+        for json in reqs.iter() {
+            let original: serde_json::Value = self::serde_json::from_str(json).unwrap();
+            let req: Request = self::serde_json::from_str(json).unwrap();
+            assert_eq!(self::serde_json::to_value(&req).unwrap(), original);
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        match Request::from_str_strict(req_in_dialog()) {
+            Ok((req, unknown)) => {
+                assert_eq!(req.version, "1.0");
+                assert_eq!(
+                    unknown,
+                    vec![
+                        "/context/Display",
+                        "/context/System/device/supportedInterfaces",
+                    ]
+                );
+            }
+            Err(e) => panic!("{}", e),
         }
     }
 
+    #[test]
+    fn test_strict_null_fields() {
+        let mut json: serde_json::Value = self::serde_json::from_str(default_req()).unwrap();
+        json["context"]["Foo"] = serde_json::Value::Null;
+        json["context"]["Bar"] = serde_json::Value::Array(Vec::new());
+        json["context"]["AudioPlayer"] = serde_json::Value::Null;
+        json["request"]["reason"] = serde_json::Value::Null;
+        let (_, unknown) = Request::from_str_strict(&json.to_string()).unwrap();
+        assert!(unknown.contains(&String::from("/context/Foo")));
+        assert!(unknown.contains(&String::from("/context/Bar")));
+        assert!(!unknown.contains(&String::from("/context/AudioPlayer")));
+        assert!(!unknown.contains(&String::from("/request/reason")));
+    }
+
     #[test]
     fn test_attribute() {
         let p: Result<Request, serde_json::Error> = self::serde_json::from_str(default_req());
@@ -414,7 +1832,7 @@ mod tests {
                 assert!(req.session.is_some());
                 assert!(req.session.unwrap().attributes.is_some());
             }
-            Err(e) => panic!(e.to_string()),
+            Err(e) => panic!("{}", e),
         }
     }
 
@@ -428,10 +1846,222 @@ mod tests {
                     "Jupiter has the shortest day of all the planets"
                 ))
             ),
-            Err(e) => panic!(e.to_string()),
+            Err(e) => panic!("{}", e),
         }
     }
 
+    #[test]
+    fn test_exception_encountered() {
+        let p: Result<Request, serde_json::Error> =
+            self::serde_json::from_str(exception_encountered_req());
+        match p {
+            Ok(req) => {
+                assert_eq!(req.reqtype(), ReqType::SystemExceptionEncountered);
+                assert_eq!(
+                    req.error().map(|e| e.error_type()),
+                    Some(ErrorType::InvalidResponse)
+                );
+                assert_eq!(
+                    req.cause_request_id(),
+                    Some("amzn1.echo-api.request.b8b49fde-4370-423f-bbb0-dc7305b788a0")
+                );
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn test_reminder_event() {
+        let req: Request = self::serde_json::from_str(reminder_event_req()).unwrap();
+        assert_eq!(req.reqtype(), ReqType::ReminderStatusChanged);
+        assert_eq!(
+            req.reminder_event(),
+            Some(ReminderEvent::StatusChanged {
+                alert_token: String::from("amzn1.ask.reminder.token"),
+                status: String::from("COMPLETED"),
+            })
+        );
+        assert_eq!(req.locale(), Locale::Unknown(String::new()));
+        let v = self::serde_json::to_value(&req).unwrap();
+        assert!(v["request"].get("locale").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "tz")]
+    fn test_local_time() {
+        let req: Request = self::serde_json::from_str(reminder_event_req()).unwrap();
+        let local = req.local_time("America/Los_Angeles").unwrap();
+        assert_eq!(local.to_rfc3339(), "2019-09-22T12:00:00-07:00");
+        assert!(req.local_time("Nowhere/Special").is_none());
+    }
+
+    #[test]
+    fn test_list_event() {
+        let req: Request = self::serde_json::from_str(list_event_req()).unwrap();
+        assert_eq!(req.reqtype(), ReqType::ListItemsCreated);
+        assert_eq!(req.reminder_event(), None);
+        assert_eq!(
+            req.list_event(),
+            Some(ListEvent::ItemsCreated {
+                list_id: String::from("list1"),
+                list_item_ids: vec![String::from("item1"), String::from("item2")],
+            })
+        );
+    }
+
+    fn list_event_req() -> &'static str {
+        r#"{
+	"version": "1.0",
+	"context": {
+		"System": {
+			"application": {
+				"applicationId": "amzn1.ask.skill.myappid"
+			},
+			"user": {
+				"userId": "amzn1.ask.account.theuserid"
+			},
+			"apiEndpoint": "https://api.amazonalexa.com",
+			"apiAccessToken": "53kr14t.k3y.d4t4-otherstuff"
+		}
+	},
+	"request": {
+		"type": "AlexaHouseholdListEvent.ItemsCreated",
+		"requestId": "amzn1.echo-api.request.list",
+		"timestamp": "2019-09-22T19:00:00Z",
+		"body": {
+			"listId": "list1",
+			"listItemIds": ["item1", "item2"]
+		}
+	}
+}"#
+    }
+
+    #[test]
+    fn test_connections_response() {
+        let req: Request = self::serde_json::from_str(connections_response_req()).unwrap();
+        assert_eq!(req.reqtype(), ReqType::ConnectionsResponse);
+        assert_eq!(req.connection_name(), Some("Buy"));
+        assert_eq!(req.connection_token(), Some("correlationToken"));
+        assert_eq!(
+            req.purchase_result(),
+            Some(PurchaseResult::AlreadyPurchased)
+        );
+        assert_eq!(req.purchase_product_id(), Some("amzn1.adg.product.1"));
+        let status = req.body.status.unwrap();
+        assert_eq!(status.code, Some(String::from("200")));
+        assert_eq!(status.message, Some(String::from("OK")));
+
+        let json = connections_response_req().replace(",\n\t\t\t\"message\": \"OK\"", "");
+        let req: Request = self::serde_json::from_str(&json).unwrap();
+        let status = req.body.status.unwrap();
+        assert_eq!(status.code, Some(String::from("200")));
+        assert_eq!(status.message, None);
+    }
+
+    fn connections_response_req() -> &'static str {
+        r#"{
+	"version": "1.0",
+	"session": {
+		"new": false,
+		"sessionId": "amzn1.echo-api.session.abc",
+		"application": {
+			"applicationId": "amzn1.ask.skill.myappid"
+		},
+		"user": {
+			"userId": "amzn1.ask.account.theuserid"
+		}
+	},
+	"context": {
+		"System": {
+			"application": {
+				"applicationId": "amzn1.ask.skill.myappid"
+			},
+			"user": {
+				"userId": "amzn1.ask.account.theuserid"
+			}
+		}
+	},
+	"request": {
+		"type": "Connections.Response",
+		"requestId": "amzn1.echo-api.request.connections",
+		"timestamp": "2019-09-22T19:00:00Z",
+		"locale": "en-US",
+		"status": {
+			"code": "200",
+			"message": "OK"
+		},
+		"name": "Buy",
+		"payload": {
+			"purchaseResult": "ALREADY_PURCHASED",
+			"productId": "amzn1.adg.product.1"
+		},
+		"token": "correlationToken"
+	}
+}"#
+    }
+
+    fn reminder_event_req() -> &'static str {
+        r#"{
+	"version": "1.0",
+	"context": {
+		"System": {
+			"application": {
+				"applicationId": "amzn1.ask.skill.myappid"
+			},
+			"user": {
+				"userId": "amzn1.ask.account.theuserid"
+			},
+			"apiEndpoint": "https://api.amazonalexa.com",
+			"apiAccessToken": "53kr14t.k3y.d4t4-otherstuff"
+		}
+	},
+	"request": {
+		"type": "Reminders.ReminderStatusChanged",
+		"requestId": "amzn1.echo-api.request.reminder",
+		"timestamp": "2019-09-22T19:00:00Z",
+		"body": {
+			"alertToken": "amzn1.ask.reminder.token",
+			"status": "COMPLETED"
+		}
+	}
+}"#
+    }
+
+    fn exception_encountered_req() -> &'static str {
+        r#"{
+	"version": "1.0",
+	"context": {
+		"System": {
+			"application": {
+				"applicationId": "amzn1.ask.skill.myappid"
+			},
+			"user": {
+				"userId": "amzn1.ask.account.theuserid"
+			},
+			"device": {
+				"deviceId": "amzn1.ask.device.superfakedevice",
+				"supportedInterfaces": {}
+			},
+			"apiEndpoint": "https://api.amazonalexa.com",
+			"apiAccessToken": "53kr14t.k3y.d4t4-otherstuff"
+		}
+	},
+	"request": {
+		"type": "System.ExceptionEncountered",
+		"requestId": "amzn1.echo-api.request.exception",
+		"timestamp": "2018-12-03T00:34:01Z",
+		"locale": "en-US",
+		"error": {
+			"type": "INVALID_RESPONSE",
+			"message": "The response was missing the required version field"
+		},
+		"cause": {
+			"requestId": "amzn1.echo-api.request.b8b49fde-4370-423f-bbb0-dc7305b788a0"
+		}
+	}
+}"#
+    }
+
     fn default_spanish_req() -> &'static str {
         r#"{
 	"version": "1.0",
@@ -622,6 +2252,98 @@ mod tests {
 }"#
     }
 
+    fn req_with_resolutions() -> &'static str {
+        r#"{
+	"version": "1.0",
+	"session": {
+		"new": false,
+		"sessionId": "amzn1.echo-api.session.blahblahblah",
+		"application": {
+			"applicationId": "amzn1.ask.skill.testappliction"
+		},
+		"user": {
+			"userId": "amzn1.ask.account.longstringuseridentifier"
+		}
+	},
+	"context": {
+		"System": {
+			"application": {
+				"applicationId": "amzn1.ask.skill.testappliction"
+			},
+			"device": {
+				"deviceId": "amzn1.ask.device.testdevice"
+			},
+			"apiEndpoint": "https://api.amazonalexa.com",
+			"apiAccessToken": "teh.token.with-long-string-more-more-more-more"
+		}
+	},
+	"request": {
+		"type": "IntentRequest",
+		"requestId": "amzn1.echo-api.request.id",
+		"timestamp": "2018-12-08T05:37:32Z",
+		"locale": "en-US",
+		"intent": {
+			"name": "paint",
+			"confirmationStatus": "NONE",
+			"slots": {
+				"color": {
+					"name": "color",
+					"value": "crimson",
+					"confirmationStatus": "NONE",
+					"resolutions": {
+						"resolutionsPerAuthority": [
+							{
+								"authority": "amzn1.er-authority.echo-sdk.dynamic.amzn1.ask.skill.testappliction.Color",
+								"status": {
+									"code": "ER_SUCCESS_MATCH"
+								},
+								"values": [
+									{
+										"value": {
+											"name": "red",
+											"id": "RED"
+										}
+									}
+								]
+							},
+							{
+								"authority": "amzn1.er-authority.echo-sdk.amzn1.ask.skill.testappliction.Color",
+								"status": {
+									"code": "ER_SUCCESS_MATCH"
+								},
+								"values": [
+									{
+										"value": {
+											"name": "dark red",
+											"id": "DARK_RED"
+										}
+									}
+								]
+							}
+						]
+					}
+				},
+				"size": {
+					"name": "size",
+					"value": "huge",
+					"confirmationStatus": "NONE",
+					"resolutions": {
+						"resolutionsPerAuthority": [
+							{
+								"authority": "amzn1.er-authority.echo-sdk.amzn1.ask.skill.testappliction.Size",
+								"status": {
+									"code": "ER_SUCCESS_NO_MATCH"
+								}
+							}
+						]
+					}
+				}
+			}
+		}
+	}
+}"#
+    }
+
     fn req_with_slots() -> &'static str {
         r#"{
 	"version": "1.0",
@@ -694,6 +2416,179 @@ mod tests {
 			}
 		}
 	}
+}"#
+    }
+
+    fn req_in_dialog() -> &'static str {
+        r#"{
+	"version": "1.0",
+	"session": {
+		"new": true,
+		"sessionId": "amzn1.echo-api.session.blahblahblah",
+		"application": {
+			"applicationId": "amzn1.ask.skill.testappliction"
+		},
+		"user": {
+			"userId": "amzn1.ask.account.longstringuseridentifier"
+		}
+	},
+	"context": {
+		"Display": {},
+		"System": {
+			"application": {
+				"applicationId": "amzn1.ask.skill.tehappz"
+			},
+			"user": {
+				"userId": "amzn1.ask.account.longstringuseridentifier"
+			},
+			"device": {
+				"deviceId": "amzn1.ask.device.testdevice",
+				"supportedInterfaces": {
+					"Display": {
+						"templateVersion": "1.0",
+						"markupVersion": "1.0"
+					}
+				}
+			},
+			"apiEndpoint": "https://api.amazonalexa.com",
+			"apiAccessToken": "teh.token.with-long-string-more-more-more-more"
+		},
+		"Viewport": {
+			"experiences": [
+				{
+					"arcMinuteWidth": 246,
+					"arcMinuteHeight": 144,
+					"canRotate": false,
+					"canResize": false
+				}
+			],
+			"shape": "RECTANGLE",
+			"pixelWidth": 1024,
+			"pixelHeight": 600,
+			"dpi": 160,
+			"currentPixelWidth": 1024,
+			"currentPixelHeight": 600,
+			"touch": [
+				"SINGLE"
+			]
+		}
+	},
+	"request": {
+		"type": "IntentRequest",
+		"requestId": "amzn1.echo-api.request.id",
+		"timestamp": "2018-12-08T05:37:32Z",
+		"locale": "en-US",
+		"dialogState": "IN_PROGRESS",
+		"intent": {
+			"name": "hello",
+			"confirmationStatus": "NONE",
+			"slots": {
+				"name": {
+					"name": "name",
+					"value": "bob",
+					"confirmationStatus": "NONE",
+					"source": "USER",
+					"slotValue": {
+						"type": "Simple",
+						"value": "bob"
+					}
+				},
+				"greeting": {
+					"name": "greeting",
+					"confirmationStatus": "NONE"
+				},
+				"toppings": {
+					"name": "toppings",
+					"confirmationStatus": "NONE",
+					"source": "USER",
+					"slotValue": {
+						"type": "List",
+						"values": [
+							{
+								"type": "Simple",
+								"value": "cheese"
+							},
+							{
+								"type": "Simple",
+								"value": "olives",
+								"resolutions": {
+									"resolutionsPerAuthority": [
+										{
+											"authority": "amzn1.er-authority.echo-sdk.amzn1.ask.skill.tehappz.Topping",
+											"status": {
+												"code": "ER_SUCCESS_MATCH"
+											},
+											"values": [
+												{
+													"value": {
+														"name": "olive",
+														"id": "OLIVE"
+													}
+												}
+											]
+										}
+									]
+								}
+							}
+						]
+					}
+				}
+			}
+		}
+	}
+}"#
+    }
+
+    fn req_with_unknown_slot_value() -> &'static str {
+        r#"{
+	"version": "1.0",
+	"session": {
+		"new": true,
+		"sessionId": "amzn1.echo-api.session.blahblahblah",
+		"application": {
+			"applicationId": "amzn1.ask.skill.testappliction"
+		},
+		"user": {
+			"userId": "amzn1.ask.account.longstringuseridentifier"
+		}
+	},
+	"context": {
+		"System": {
+			"application": {
+				"applicationId": "amzn1.ask.skill.tehappz"
+			},
+			"user": {
+				"userId": "amzn1.ask.account.longstringuseridentifier"
+			}
+		}
+	},
+	"request": {
+		"type": "IntentRequest",
+		"requestId": "amzn1.echo-api.request.id",
+		"timestamp": "2018-12-08T05:37:32Z",
+		"locale": "en-US",
+		"intent": {
+			"name": "directions",
+			"confirmationStatus": "NONE",
+			"slots": {
+				"route": {
+					"name": "route",
+					"value": "home",
+					"confirmationStatus": "NONE",
+					"source": "USER",
+					"slotValue": {
+						"type": "Composite",
+						"slots": {
+							"destination": {
+								"type": "Simple",
+								"value": "home"
+							}
+						}
+					}
+				}
+			}
+		}
+	}
 }"#
     }
 }
@@ -0,0 +1,391 @@
+//! Fluent construction of `Request` values in code, e.g. for unit-testing handlers
+//!
+//! ```rust
+//! # use alexa_sdk::request::Locale;
+//! # use alexa_sdk::request_builder::RequestBuilder;
+//! let req = RequestBuilder::intent("hello")
+//!     .slot("name", "bob")
+//!     .locale(Locale::German)
+//!     .attribute("lastSpeech", "hallo")
+//!     .build();
+//! ```
+
+use crate::request::{
+    Application, Context, Device, Extra, Intent, Locale, Person, ReqBody, Request, Resolution,
+    ResolutionsPerAuthority, Session, Slot, Status, System, Unit, User, Value, ValueWrapper,
+    Viewport,
+};
+use crate::util::{generate_id, timestamp_now};
+use std::collections::HashMap;
+
+/// Builder for `Request` values with defaults for everything not set explicitly:
+/// generated request and session IDs, the current time, the `en-US` locale and a new session
+#[derive(Debug, Clone)]
+pub struct RequestBuilder {
+    reqtype: String,
+    request_id: String,
+    timestamp: String,
+    locale: Locale,
+    intent: Option<Intent>,
+    reason: Option<String>,
+    dialog_state: Option<String>,
+    session_id: String,
+    new_session: bool,
+    attributes: Option<HashMap<String, String>>,
+    application_id: String,
+    user_id: String,
+    access_token: Option<String>,
+    api_access_token: Option<String>,
+    api_endpoint: String,
+    device_id: String,
+    person: Option<Person>,
+    unit: Option<Unit>,
+    viewport: Option<Viewport>,
+}
+
+impl RequestBuilder {
+    /// Constructs a builder for a request of the given type, e.g. `LaunchRequest`
+    pub fn new(reqtype: &str) -> RequestBuilder {
+        RequestBuilder {
+            reqtype: String::from(reqtype),
+            request_id: format!("amzn1.echo-api.request.{}", generate_id()),
+            timestamp: timestamp_now(),
+            locale: Locale::AmericanEnglish,
+            intent: None,
+            reason: None,
+            dialog_state: None,
+            session_id: format!("amzn1.echo-api.session.{}", generate_id()),
+            new_session: true,
+            attributes: None,
+            application_id: String::from("amzn1.ask.skill.test"),
+            user_id: String::from("amzn1.ask.account.test"),
+            access_token: None,
+            api_access_token: None,
+            api_endpoint: String::from("https://api.amazonalexa.com"),
+            device_id: String::from("amzn1.ask.device.test"),
+            person: None,
+            unit: None,
+            viewport: None,
+        }
+    }
+
+    /// Constructs a builder for a `LaunchRequest`
+    pub fn launch() -> RequestBuilder {
+        RequestBuilder::new("LaunchRequest")
+    }
+
+    /// Constructs a builder for an `IntentRequest` for the named intent
+    pub fn intent(name: &str) -> RequestBuilder {
+        let mut b = RequestBuilder::new("IntentRequest");
+        b.intent = Some(Intent {
+            name: String::from(name),
+            confirmation_status: Some(String::from("NONE")),
+            slots: None,
+            extra: Extra::new(),
+        });
+        b
+    }
+
+    /// Constructs a builder for a `SessionEndedRequest` with the given reason, e.g. `USER_INITIATED`
+    pub fn session_ended(reason: &str) -> RequestBuilder {
+        let mut b = RequestBuilder::new("SessionEndedRequest");
+        b.reason = Some(String::from(reason));
+        b
+    }
+
+    /// sets the request ID
+    pub fn request_id(mut self, id: &str) -> Self {
+        self.request_id = String::from(id);
+        self
+    }
+
+    /// sets the request timestamp, in ISO-8601 format
+    pub fn timestamp(mut self, timestamp: &str) -> Self {
+        self.timestamp = String::from(timestamp);
+        self
+    }
+
+    /// sets the request locale
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// sets the dialog state, e.g. `IN_PROGRESS`
+    pub fn dialog_state(mut self, state: &str) -> Self {
+        self.dialog_state = Some(String::from(state));
+        self
+    }
+
+    /// sets the intent confirmation status, e.g. `CONFIRMED`
+    pub fn intent_confirmation(mut self, status: &str) -> Self {
+        if let Some(ref mut i) = self.intent {
+            i.confirmation_status = Some(String::from(status));
+        }
+        self
+    }
+
+    /// adds a slot to the intent
+    pub fn add_slot(mut self, slot: Slot) -> Self {
+        if let Some(ref mut i) = self.intent {
+            i.slots
+                .get_or_insert_with(HashMap::new)
+                .insert(slot.name.clone(), slot);
+        }
+        self
+    }
+
+    /// adds a slot with the given value to the intent
+    pub fn slot(self, name: &str, value: &str) -> Self {
+        self.add_slot(new_slot(name, Some(value), None))
+    }
+
+    /// adds a slot that the user did not fill to the intent
+    pub fn empty_slot(self, name: &str) -> Self {
+        self.add_slot(new_slot(name, None, None))
+    }
+
+    /// adds a slot with the given value, resolved by the skill's static slot type
+    /// values to the given canonical value and id
+    pub fn resolved_slot(self, name: &str, value: &str, resolved: &str, id: &str) -> Self {
+        let resolution = Resolution {
+            resolutions_per_authority: vec![ResolutionsPerAuthority {
+                authority: format!(
+                    "amzn1.er-authority.echo-sdk.{}.{}",
+                    self.application_id, name
+                ),
+                status: Status {
+                    code: String::from("ER_SUCCESS_MATCH"),
+                    extra: Extra::new(),
+                },
+                values: vec![ValueWrapper {
+                    value: Value {
+                        name: String::from(resolved),
+                        id: String::from(id),
+                        extra: Extra::new(),
+                    },
+                    extra: Extra::new(),
+                }],
+                extra: Extra::new(),
+            }],
+            extra: Extra::new(),
+        };
+        self.add_slot(new_slot(name, Some(value), Some(resolution)))
+    }
+
+    /// sets the session ID
+    pub fn session_id(mut self, id: &str) -> Self {
+        self.session_id = String::from(id);
+        self
+    }
+
+    /// sets whether this is the first request of a session
+    pub fn new_session(mut self, new: bool) -> Self {
+        self.new_session = new;
+        self
+    }
+
+    /// adds a session attribute
+    pub fn attribute(mut self, key: &str, val: &str) -> Self {
+        self.attributes
+            .get_or_insert_with(HashMap::new)
+            .insert(String::from(key), String::from(val));
+        self
+    }
+
+    /// sets the session attributes
+    pub fn attributes(mut self, attributes: Option<HashMap<String, String>>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    /// sets the skill application ID
+    pub fn application_id(mut self, id: &str) -> Self {
+        self.application_id = String::from(id);
+        self
+    }
+
+    /// sets the user ID
+    pub fn user_id(mut self, id: &str) -> Self {
+        self.user_id = String::from(id);
+        self
+    }
+
+    /// sets the account linking access token of the user
+    pub fn access_token(mut self, token: &str) -> Self {
+        self.access_token = Some(String::from(token));
+        self
+    }
+
+    /// sets the access token for Alexa service APIs
+    pub fn api_access_token(mut self, token: &str) -> Self {
+        self.api_access_token = Some(String::from(token));
+        self
+    }
+
+    /// sets the base URL for Alexa service APIs
+    pub fn api_endpoint(mut self, url: &str) -> Self {
+        self.api_endpoint = String::from(url);
+        self
+    }
+
+    /// sets the device ID
+    pub fn device_id(mut self, id: &str) -> Self {
+        self.device_id = String::from(id);
+        self
+    }
+
+    /// sets the recognized speaker
+    pub fn person_id(mut self, id: &str) -> Self {
+        self.person = Some(Person {
+            person_id: String::from(id),
+            access_token: None,
+            extra: Extra::new(),
+        });
+        self
+    }
+
+    /// sets the account linking access token of the recognized speaker
+    pub fn person_access_token(mut self, token: &str) -> Self {
+        if let Some(ref mut p) = self.person {
+            p.access_token = Some(String::from(token));
+        }
+        self
+    }
+
+    /// sets the organizational unit of the device
+    pub fn unit_id(mut self, id: &str) -> Self {
+        self.unit = Some(Unit {
+            unit_id: String::from(id),
+            persistent_unit_id: None,
+            extra: Extra::new(),
+        });
+        self
+    }
+
+    /// sets the device screen characteristics
+    pub fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    /// Constructs the request
+    pub fn build(self) -> Request {
+        let application = Application {
+            application_id: self.application_id,
+            extra: Extra::new(),
+        };
+        let user = User {
+            user_id: self.user_id,
+            access_token: self.access_token,
+            extra: Extra::new(),
+        };
+        Request {
+            version: String::from("1.0"),
+            session: Some(Session {
+                new: self.new_session,
+                session_id: self.session_id,
+                attributes: self.attributes,
+                application: application.clone(),
+                user: user.clone(),
+                extra: Extra::new(),
+            }),
+            body: ReqBody {
+                reqtype: self.reqtype,
+                request_id: self.request_id,
+                timestamp: self.timestamp,
+                locale: self.locale.to_string(),
+                intent: self.intent,
+                reason: self.reason,
+                dialog_state: self.dialog_state,
+                error: None,
+                cause: None,
+                event: None,
+                name: None,
+                status: None,
+                payload: None,
+                token: None,
+                extra: Extra::new(),
+            },
+            context: Context {
+                system: System {
+                    api_access_token: self.api_access_token,
+                    api_endpoint: Some(self.api_endpoint),
+                    device: Some(Device {
+                        device_id: self.device_id,
+                        extra: Extra::new(),
+                    }),
+                    application: Some(application),
+                    user: Some(user),
+                    person: self.person,
+                    unit: self.unit,
+                    extra: Extra::new(),
+                },
+                audio_player: None,
+                viewport: self.viewport,
+                extra: Extra::new(),
+            },
+            extra: Extra::new(),
+        }
+    }
+}
+
+fn new_slot(name: &str, value: Option<&str>, resolutions: Option<Resolution>) -> Slot {
+    Slot {
+        name: String::from(name),
+        value: value.map(String::from),
+        confirmation_status: Some(String::from("NONE")),
+        source: value.map(|_| String::from("USER")),
+        resolutions,
+        slot_value: None,
+        extra: Extra::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::{IntentType, ReqType};
+
+    #[test]
+    fn test_defaults() {
+        let req = RequestBuilder::launch().build();
+        assert_eq!(req.reqtype(), ReqType::LaunchRequest);
+        assert_eq!(req.locale(), Locale::AmericanEnglish);
+        assert!(req.is_new());
+        assert!(req.body.request_id.starts_with("amzn1.echo-api.request."));
+        assert_eq!(req.body.timestamp.len(), "2019-01-01T00:00:00Z".len());
+        assert_ne!(
+            RequestBuilder::launch().build().body.request_id,
+            req.body.request_id
+        );
+    }
+
+    #[test]
+    fn test_intent() {
+        let req = RequestBuilder::intent("hello")
+            .slot("name", "bob")
+            .empty_slot("greeting")
+            .resolved_slot("color", "crimson", "red", "RED")
+            .locale(Locale::German)
+            .attribute("lastSpeech", "hallo")
+            .new_session(false)
+            .api_access_token("token")
+            .person_id("amzn1.ask.person.test")
+            .build();
+        assert_eq!(req.intent(), IntentType::User(String::from("hello")));
+        assert_eq!(req.slot_value("name"), Some(Some(String::from("bob"))));
+        assert_eq!(req.slot_value("greeting"), Some(None));
+        assert_eq!(req.slot_resolved_id("color"), Some(String::from("RED")));
+        assert_eq!(req.locale(), Locale::German);
+        assert_eq!(
+            req.attribute_value("lastSpeech"),
+            Some(&String::from("hallo"))
+        );
+        assert!(!req.is_new());
+        assert_eq!(
+            req.context.system.person.unwrap().person_id,
+            "amzn1.ask.person.test"
+        );
+    }
+}
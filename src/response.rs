@@ -3,6 +3,7 @@ extern crate serde_derive;
 extern crate serde_json;
 
 use self::serde_derive::{Deserialize, Serialize};
+use self::serde_json::Value;
 use std::collections::HashMap;
 use std::fmt;
 
@@ -29,6 +30,7 @@ impl Response {
                 output_speech: None,
                 card: None,
                 reprompt: None,
+                directives: None,
                 should_end_session: should_end,
             },
         }
@@ -63,6 +65,63 @@ impl Response {
         self
     }
 
+    /// adds a reprompt to the response, spoken if the user does not reply
+    pub fn reprompt(mut self, speech: Speech) -> Self {
+        self.body.reprompt = Some(Reprompt {
+            output_speech: speech,
+        });
+        self
+    }
+
+    /// adds a directive to the response
+    pub fn directive(mut self, directive: Directive) -> Self {
+        self.body
+            .directives
+            .get_or_insert_with(Vec::new)
+            .push(directive);
+        self
+    }
+
+    /// returns the response version
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// returns the response body
+    pub fn body(&self) -> &ResBody {
+        &self.body
+    }
+
+    /// returns the session attributes set on the response, if any
+    pub fn session_attributes(&self) -> Option<&HashMap<String, String>> {
+        self.session_attributes.as_ref()
+    }
+
+    /// returns the session attribute value with the given key, if it exists
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        Some(self.session_attributes.as_ref()?.get(key)?.as_str())
+    }
+
+    /// returns the output speech text (or SSML), if any
+    pub fn speech_text(&self) -> Option<&str> {
+        self.body.output_speech.as_ref()?.content()
+    }
+
+    /// returns the reprompt speech text (or SSML), if any
+    pub fn reprompt_text(&self) -> Option<&str> {
+        self.body.reprompt.as_ref()?.output_speech.content()
+    }
+
+    /// returns the directives in the response
+    pub fn directives(&self) -> &[Directive] {
+        self.body.directives.as_deref().unwrap_or(&[])
+    }
+
+    /// returns whether the response ends the session
+    pub fn should_end_session(&self) -> bool {
+        self.body.should_end_session
+    }
+
     /// adds an attribute key/value pair to the response
     /// attributes can be read on the next request for basic state
     /// persistance
@@ -97,10 +156,39 @@ pub struct ResBody {
     card: Option<Card>,
     #[serde(skip_serializing_if = "Option::is_none")]
     reprompt: Option<Reprompt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    directives: Option<Vec<Directive>>,
     #[serde(rename = "shouldEndSession")]
     should_end_session: bool,
 }
 
+impl ResBody {
+    /// returns the output speech, if any
+    pub fn output_speech(&self) -> Option<&Speech> {
+        self.output_speech.as_ref()
+    }
+
+    /// returns the card, if any
+    pub fn card(&self) -> Option<&Card> {
+        self.card.as_ref()
+    }
+
+    /// returns the reprompt, if any
+    pub fn reprompt(&self) -> Option<&Reprompt> {
+        self.reprompt.as_ref()
+    }
+
+    /// returns the directives
+    pub fn directives(&self) -> &[Directive] {
+        self.directives.as_deref().unwrap_or(&[])
+    }
+
+    /// returns whether the response ends the session
+    pub fn should_end_session(&self) -> bool {
+        self.should_end_session
+    }
+}
+
 enum SpeechType {
     Plain,
     Ssml,
@@ -168,6 +256,26 @@ impl Speech {
         }
     }
 
+    /// returns the speech type, `PlainText` or `SSML`
+    pub fn speech_type(&self) -> &str {
+        &self.speech_type
+    }
+
+    /// returns the plain text, for plain text speech
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// returns the SSML markup, for SSML speech
+    pub fn markup(&self) -> Option<&str> {
+        self.ssml.as_deref()
+    }
+
+    /// returns the plain text or SSML, whichever is set
+    pub fn content(&self) -> Option<&str> {
+        self.text().or_else(|| self.markup())
+    }
+
     /// Adds play behavior to a speech object
     pub fn play_behavior(&mut self, behavior: PlayBehavior) {
         self.play_behavior = Some(behavior.to_string());
@@ -189,7 +297,7 @@ impl fmt::Display for CardType {
             CardType::Simple => "Simple",
             CardType::Standard => "Standard",
             CardType::LinkAccount => "LinkAccount",
-            CardType::AskForPermission => "AskForPermissonConsent",
+            CardType::AskForPermission => "AskForPermissionsConsent",
         };
         write!(f, "{}", s)
     }
@@ -259,6 +367,36 @@ impl Card {
             permissions: Some(permissions),
         }
     }
+
+    /// returns the card type, e.g. `Simple`
+    pub fn card_type(&self) -> &str {
+        &self.card_type
+    }
+
+    /// returns the card title, if any
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// returns the content of a simple card, if any
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+
+    /// returns the text of a standard card, if any
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// returns the image of a standard card, if any
+    pub fn image(&self) -> Option<&Image> {
+        self.image.as_ref()
+    }
+
+    /// returns the permissions requested by a permissions card, if any
+    pub fn permissions(&self) -> Option<&[String]> {
+        self.permissions.as_deref()
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -267,7 +405,87 @@ pub struct Reprompt {
     output_speech: Speech,
 }
 
+impl Reprompt {
+    /// returns the reprompt speech
+    pub fn output_speech(&self) -> &Speech {
+        &self.output_speech
+    }
+}
+
+/// A directive instructing the device to take an action, such as `Dialog.Delegate`
 #[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Directive {
+    #[serde(rename = "type")]
+    directive_type: String,
+    #[serde(flatten)]
+    fields: HashMap<String, Value>,
+}
+
+impl Directive {
+    /// Constructs a directive of the given type with no other fields
+    pub fn new(directive_type: &str) -> Directive {
+        Directive {
+            directive_type: String::from(directive_type),
+            fields: HashMap::new(),
+        }
+    }
+
+    /// adds a field to the directive
+    pub fn field(mut self, key: &str, value: Value) -> Self {
+        self.fields.insert(String::from(key), value);
+        self
+    }
+
+    /// Constructs a `Connections.SendRequest` directive starting the purchase of an
+    /// in-skill product; the token is returned in the `Connections.Response` request
+    pub fn buy(product_id: &str, token: &str) -> Directive {
+        Directive::send_request("Buy", product_id, token, None)
+    }
+
+    /// Constructs a `Connections.SendRequest` directive offering an in-skill product
+    /// with the given message
+    pub fn upsell(product_id: &str, message: &str, token: &str) -> Directive {
+        Directive::send_request("Upsell", product_id, token, Some(message))
+    }
+
+    /// Constructs a `Connections.SendRequest` directive cancelling a subscription
+    /// or refunding an entitlement
+    pub fn cancel(product_id: &str, token: &str) -> Directive {
+        Directive::send_request("Cancel", product_id, token, None)
+    }
+
+    fn send_request(name: &str, product_id: &str, token: &str, message: Option<&str>) -> Directive {
+        let mut product = self::serde_json::Map::new();
+        product.insert(
+            String::from("productId"),
+            Value::String(String::from(product_id)),
+        );
+        let mut payload = self::serde_json::Map::new();
+        payload.insert(String::from("InSkillProduct"), Value::Object(product));
+        if let Some(m) = message {
+            payload.insert(
+                String::from("upsellMessage"),
+                Value::String(String::from(m)),
+            );
+        }
+        Directive::new("Connections.SendRequest")
+            .field("name", Value::String(String::from(name)))
+            .field("payload", Value::Object(payload))
+            .field("token", Value::String(String::from(token)))
+    }
+
+    /// returns the directive type
+    pub fn directive_type(&self) -> &str {
+        &self.directive_type
+    }
+
+    /// returns the directive field with the given key, if it exists
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.fields.get(key)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Image {
     #[serde(rename = "smallImageUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -291,14 +509,15 @@ impl Image {
         self.large_image_url = Some(url);
         self
     }
-}
 
-impl Default for Image {
-    fn default() -> Self {
-        Image {
-            small_image_url: None,
-            large_image_url: None,
-        }
+    /// returns the small image URL, if any
+    pub fn small_url(&self) -> Option<&str> {
+        self.small_image_url.as_deref()
+    }
+
+    /// returns the large image URL, if any
+    pub fn large_url(&self) -> Option<&str> {
+        self.large_image_url.as_deref()
     }
 }
 
@@ -306,6 +525,24 @@ impl Default for Image {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_purchase_directives() {
+        let d = Directive::upsell("amzn1.adg.product.1", "Want more?", "token1");
+        let v = self::serde_json::to_value(&d).unwrap();
+        assert_eq!(v["type"], "Connections.SendRequest");
+        assert_eq!(v["name"], "Upsell");
+        assert_eq!(
+            v["payload"]["InSkillProduct"]["productId"],
+            "amzn1.adg.product.1"
+        );
+        assert_eq!(v["payload"]["upsellMessage"], "Want more?");
+        assert_eq!(v["token"], "token1");
+
+        let v = self::serde_json::to_value(Directive::buy("p", "t")).unwrap();
+        assert_eq!(v["name"], "Buy");
+        assert!(v["payload"].get("upsellMessage").is_none());
+    }
+
     #[test]
     fn test_version() {
         let r = Response::simple("hello, world", "hello, dude");
@@ -338,6 +575,18 @@ mod tests {
         assert_eq!(attr, "value");
     }
 
+    #[test]
+    fn test_image_urls() {
+        let card = Card::standard(
+            "foo",
+            "bar",
+            Image::new().small_image_url(String::from("baaz.png")),
+        );
+        let image = card.image().unwrap();
+        assert_eq!(image.small_url(), Some("baaz.png"));
+        assert_eq!(image.large_url(), None);
+    }
+
     #[test]
     fn test_builder_with_image_builder() {
         let mut res = Response::new(false)
@@ -405,9 +654,29 @@ mod tests {
         assert_eq!(r.body.card.unwrap().content.unwrap(), t);
     }
 
+    #[test]
+    fn test_accessors() {
+        let mut res = Response::new(false)
+            .card(Card::simple("foo", "bar"))
+            .speech(Speech::ssml("<speak>hello</speak>"))
+            .reprompt(Speech::plain("still there?"))
+            .directive(Directive::new("Dialog.Delegate"));
+        res.add_attribute("attr", "value");
+        assert_eq!(res.version(), "1.0");
+        assert_eq!(res.speech_text(), Some("<speak>hello</speak>"));
+        assert_eq!(res.body().output_speech().unwrap().speech_type(), "SSML");
+        assert_eq!(res.reprompt_text(), Some("still there?"));
+        let card = res.body().card().unwrap();
+        assert_eq!(card.title(), Some("foo"));
+        assert_eq!(card.content(), Some("bar"));
+        assert_eq!(res.attribute("attr"), Some("value"));
+        assert_eq!(res.directives()[0].directive_type(), "Dialog.Delegate");
+        assert!(!res.should_end_session());
+    }
+
     #[test]
     fn test_should_end() {
         let r = Response::simple("foo", "bar");
-        assert_eq!(r.body.should_end_session, true);
+        assert!(r.body.should_end_session);
     }
 }
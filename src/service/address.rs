@@ -0,0 +1,176 @@
+//! Client for the [Device Address API](https://developer.amazon.com/docs/custom-skills/device-address-api.html)
+//!
+//! ```rust,no_run
+//! # use alexa_sdk::response::{Response, Speech};
+//! # use alexa_sdk::service::address::AddressClient;
+//! # use alexa_sdk::request_builder::RequestBuilder;
+//! # use alexa_sdk::service::mock::MockTransport;
+//! # fn main() -> Result<(), alexa_sdk::service::Error> {
+//! # let req = RequestBuilder::launch().api_access_token("token").build();
+//! # let transport = MockTransport::new();
+//! let client = AddressClient::from_request(&req, transport)?;
+//! let res = match client.full_address() {
+//!     Ok(Some(addr)) => Response::end().speech(Speech::plain(&format!(
+//!         "You are in {}",
+//!         addr.city.unwrap_or_default()
+//!     ))),
+//!     // no address is set for the device
+//!     Ok(None) => Response::end().speech(Speech::plain("Please set your address")),
+//!     Err(e) => match e.permission_card() {
+//!         Some(card) => Response::end()
+//!             .speech(Speech::plain("Please grant access"))
+//!             .card(card),
+//!         None => return Err(e),
+//!     },
+//! };
+//! # let _ = res;
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate serde_derive;
+
+use self::serde_derive::{Deserialize, Serialize};
+use super::{device_id, Error, ServiceClient, Transport};
+use crate::request::Request;
+
+/// permission scope for the full address of the device
+pub const FULL_ADDRESS_SCOPE: &str = "read::alexa:device:all:address";
+/// permission scope for the country and postal code of the device
+pub const COUNTRY_AND_POSTAL_CODE_SCOPE: &str =
+    "read::alexa:device:all:address:country_and_postal_code";
+
+/// The address set for a device
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Address {
+    pub address_line1: Option<String>,
+    pub address_line2: Option<String>,
+    pub address_line3: Option<String>,
+    pub city: Option<String>,
+    pub district_or_county: Option<String>,
+    pub state_or_region: Option<String>,
+    pub country_code: Option<String>,
+    pub postal_code: Option<String>,
+}
+
+/// The country and postal code set for a device
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CountryAndPostalCode {
+    pub country_code: Option<String>,
+    pub postal_code: Option<String>,
+}
+
+/// Client for the address of the device a request came from
+pub struct AddressClient<T> {
+    client: ServiceClient<T>,
+    device_id: String,
+}
+
+impl<T: Transport> AddressClient<T> {
+    /// Constructs a client for the given device
+    pub fn new(client: ServiceClient<T>, device_id: &str) -> AddressClient<T> {
+        AddressClient {
+            client,
+            device_id: String::from(device_id),
+        }
+    }
+
+    /// Constructs a client for the device, API endpoint and access token of a request
+    pub fn from_request(req: &Request, transport: T) -> Result<AddressClient<T>, Error> {
+        let device_id = device_id(req)?;
+        Ok(AddressClient::new(
+            ServiceClient::from_request(req, transport)?,
+            device_id,
+        ))
+    }
+
+    /// retrieves the full address of the device, or None if no address is set
+    /// fails with `Error::PermissionRequired` if the user has not granted `FULL_ADDRESS_SCOPE`
+    pub fn full_address(&self) -> Result<Option<Address>, Error> {
+        let path = format!("/v1/devices/{}/settings/address", self.device_id);
+        self.client
+            .get(&path)
+            .map_err(|e| e.require_permissions(&[FULL_ADDRESS_SCOPE]))
+    }
+
+    /// retrieves the country and postal code of the device, or None if no address is set
+    /// fails with `Error::PermissionRequired` if the user has not granted
+    /// `COUNTRY_AND_POSTAL_CODE_SCOPE`
+    pub fn country_and_postal_code(&self) -> Result<Option<CountryAndPostalCode>, Error> {
+        let path = format!(
+            "/v1/devices/{}/settings/address/countryAndPostalCode",
+            self.device_id
+        );
+        self.client
+            .get(&path)
+            .map_err(|e| e.require_permissions(&[COUNTRY_AND_POSTAL_CODE_SCOPE]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockTransport;
+    use super::super::Method;
+    use super::*;
+    use crate::request_builder::RequestBuilder;
+
+    #[test]
+    fn test_address() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Method::Get,
+            "/v1/devices/dev1/settings/address",
+            200,
+            r#"{
+                "addressLine1": "410 Terry Ave North",
+                "addressLine2": "",
+                "addressLine3": "aeiou",
+                "city": "Seattle",
+                "countryCode": "US",
+                "districtOrCounty": "",
+                "postalCode": "98109",
+                "stateOrRegion": "WA"
+            }"#,
+        );
+        mock.respond(
+            Method::Get,
+            "/v1/devices/dev1/settings/address/countryAndPostalCode",
+            403,
+            "",
+        );
+        let req = RequestBuilder::launch()
+            .device_id("dev1")
+            .api_access_token("token")
+            .build();
+        let client = AddressClient::from_request(&req, &mock).unwrap();
+        let addr = client.full_address().unwrap().unwrap();
+        assert_eq!(addr.city, Some(String::from("Seattle")));
+        assert_eq!(addr.postal_code, Some(String::from("98109")));
+
+        let e = client.country_and_postal_code().unwrap_err();
+        let card = e.permission_card().unwrap();
+        assert_eq!(card.card_type(), "AskForPermissionsConsent");
+        assert_eq!(
+            card.permissions(),
+            Some(&[String::from(COUNTRY_AND_POSTAL_CODE_SCOPE)][..])
+        );
+    }
+
+    #[test]
+    fn test_no_address() {
+        let mock = MockTransport::new();
+        mock.respond(Method::Get, "/v1/devices/dev1/settings/address", 204, "");
+        mock.respond(
+            Method::Get,
+            "/v1/devices/dev1/settings/address/countryAndPostalCode",
+            204,
+            "",
+        );
+        let client = ServiceClient::new("https://api.amazonalexa.com", "token", &mock);
+        let client = AddressClient::new(client, "dev1");
+        assert_eq!(client.full_address().unwrap(), None);
+        assert_eq!(client.country_and_postal_code().unwrap(), None);
+    }
+}
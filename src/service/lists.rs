@@ -0,0 +1,328 @@
+//! Client for the [List Management API](https://developer.amazon.com/docs/custom-skills/access-the-alexa-shopping-and-to-do-lists.html)
+//!
+//! ```rust,no_run
+//! # use alexa_sdk::service::lists::ListsClient;
+//! # use alexa_sdk::request_builder::RequestBuilder;
+//! # use alexa_sdk::service::mock::MockTransport;
+//! # fn main() -> Result<(), alexa_sdk::service::Error> {
+//! # let req = RequestBuilder::launch().api_access_token("token").build();
+//! # let transport = MockTransport::new();
+//! let lists = ListsClient::from_request(&req, transport)?;
+//! if let Some(id) = lists.shopping_list_id()? {
+//!     lists.create_item(&id, "milk")?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Changes to household lists are sent to the skill as `AlexaHouseholdListEvent.*`
+//! requests; see `Request::list_event`.
+
+extern crate serde_derive;
+
+use self::serde_derive::{Deserialize, Serialize};
+use super::{Error, ServiceClient, Transport};
+use crate::request::Request;
+use std::fmt;
+
+/// permission scope for reading household lists
+pub const READ_SCOPE: &str = "read::alexa:household:list";
+/// permission scope for writing household lists
+pub const WRITE_SCOPE: &str = "write::alexa:household:list";
+
+/// name of the default shopping list
+pub const SHOPPING_LIST: &str = "Alexa shopping list";
+/// name of the default to-do list
+pub const TODO_LIST: &str = "Alexa to-do list";
+
+/// State of a list, or status of a list item
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ListStatus {
+    Active,
+    Completed,
+    Archived,
+}
+
+impl fmt::Display for ListStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            ListStatus::Active => "active",
+            ListStatus::Completed => "completed",
+            ListStatus::Archived => "archived",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Link to the items of a list with a given status
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StatusLink {
+    pub href: String,
+    pub status: String,
+}
+
+/// Metadata of a household list
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ListMetadata {
+    pub list_id: String,
+    pub name: String,
+    pub state: String,
+    pub version: u32,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub status_map: Vec<StatusLink>,
+}
+
+/// Metadata of all of the household lists
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Lists {
+    #[serde(default)]
+    pub lists: Vec<ListMetadata>,
+}
+
+impl Lists {
+    /// returns the list with the given name, if any
+    pub fn by_name(&self, name: &str) -> Option<&ListMetadata> {
+        self.lists.iter().find(|l| l.name == name)
+    }
+}
+
+/// An item on a list
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ListItem {
+    pub id: String,
+    pub version: u32,
+    pub value: String,
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub href: Option<String>,
+}
+
+/// A household list with its items of one status
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct List {
+    pub list_id: String,
+    pub name: String,
+    pub state: String,
+    pub version: u32,
+    #[serde(default)]
+    pub items: Vec<ListItem>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ListBody<'a> {
+    name: &'a str,
+    state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u32>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ItemBody<'a> {
+    value: &'a str,
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u32>,
+}
+
+/// Client for the household lists of the user of a request
+pub struct ListsClient<T> {
+    client: ServiceClient<T>,
+}
+
+impl<T: Transport> ListsClient<T> {
+    /// Constructs a client using the given service client
+    pub fn new(client: ServiceClient<T>) -> ListsClient<T> {
+        ListsClient { client }
+    }
+
+    /// Constructs a client for the API endpoint and access token of a request
+    pub fn from_request(req: &Request, transport: T) -> Result<ListsClient<T>, Error> {
+        Ok(ListsClient::new(ServiceClient::from_request(
+            req, transport,
+        )?))
+    }
+
+    /// retrieves the metadata of all of the household lists
+    pub fn lists(&self) -> Result<Lists, Error> {
+        self.client
+            .get("/v2/householdlists/")
+            .map_err(|e| e.require_permissions(&[READ_SCOPE]))
+    }
+
+    /// retrieves the ID of the default shopping list
+    pub fn shopping_list_id(&self) -> Result<Option<String>, Error> {
+        Ok(self
+            .lists()?
+            .by_name(SHOPPING_LIST)
+            .map(|l| l.list_id.clone()))
+    }
+
+    /// retrieves the ID of the default to-do list
+    pub fn todo_list_id(&self) -> Result<Option<String>, Error> {
+        Ok(self.lists()?.by_name(TODO_LIST).map(|l| l.list_id.clone()))
+    }
+
+    /// retrieves a list with its items of the given status
+    pub fn list(&self, list_id: &str, status: ListStatus) -> Result<List, Error> {
+        self.client
+            .get(&format!("/v2/householdlists/{}/{}", list_id, status))
+            .map_err(|e| e.require_permissions(&[READ_SCOPE]))
+    }
+
+    /// creates an active custom list
+    pub fn create_list(&self, name: &str) -> Result<ListMetadata, Error> {
+        let body = ListBody {
+            name,
+            state: ListStatus::Active.to_string(),
+            version: None,
+        };
+        self.client
+            .post("/v2/householdlists/", &body)
+            .map_err(|e| e.require_permissions(&[WRITE_SCOPE]))
+    }
+
+    /// renames or archives a custom list, given the version last retrieved
+    pub fn update_list(
+        &self,
+        list_id: &str,
+        name: &str,
+        state: ListStatus,
+        version: u32,
+    ) -> Result<ListMetadata, Error> {
+        let body = ListBody {
+            name,
+            state: state.to_string(),
+            version: Some(version),
+        };
+        self.client
+            .put(&format!("/v2/householdlists/{}", list_id), &body)
+            .map_err(|e| e.require_permissions(&[WRITE_SCOPE]))
+    }
+
+    /// deletes a custom list
+    pub fn delete_list(&self, list_id: &str) -> Result<(), Error> {
+        self.client
+            .delete(&format!("/v2/householdlists/{}", list_id))
+            .map_err(|e| e.require_permissions(&[WRITE_SCOPE]))
+    }
+
+    /// retrieves an item on a list
+    pub fn item(&self, list_id: &str, item_id: &str) -> Result<ListItem, Error> {
+        self.client
+            .get(&format!("/v2/householdlists/{}/items/{}", list_id, item_id))
+            .map_err(|e| e.require_permissions(&[READ_SCOPE]))
+    }
+
+    /// adds an active item to a list
+    pub fn create_item(&self, list_id: &str, value: &str) -> Result<ListItem, Error> {
+        let body = ItemBody {
+            value,
+            status: ListStatus::Active.to_string(),
+            version: None,
+        };
+        self.client
+            .post(&format!("/v2/householdlists/{}/items", list_id), &body)
+            .map_err(|e| e.require_permissions(&[WRITE_SCOPE]))
+    }
+
+    /// changes the value or status of an item, given the version last retrieved
+    pub fn update_item(
+        &self,
+        list_id: &str,
+        item_id: &str,
+        value: &str,
+        status: ListStatus,
+        version: u32,
+    ) -> Result<ListItem, Error> {
+        let body = ItemBody {
+            value,
+            status: status.to_string(),
+            version: Some(version),
+        };
+        self.client
+            .put(
+                &format!("/v2/householdlists/{}/items/{}", list_id, item_id),
+                &body,
+            )
+            .map_err(|e| e.require_permissions(&[WRITE_SCOPE]))
+    }
+
+    /// deletes an item from a list
+    pub fn delete_item(&self, list_id: &str, item_id: &str) -> Result<(), Error> {
+        self.client
+            .delete(&format!("/v2/householdlists/{}/items/{}", list_id, item_id))
+            .map_err(|e| e.require_permissions(&[WRITE_SCOPE]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockTransport;
+    use super::super::Method;
+    use super::*;
+    use crate::request_builder::RequestBuilder;
+
+    #[test]
+    fn test_lists() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Method::Get,
+            "/v2/householdlists/",
+            200,
+            r#"{"lists": [
+                {"listId": "shop1", "name": "Alexa shopping list", "state": "active", "version": 1,
+                 "statusMap": [{"href": "/v2/householdlists/shop1/active", "status": "active"}]},
+                {"listId": "todo1", "name": "Alexa to-do list", "state": "active", "version": 1}
+            ]}"#,
+        );
+        mock.respond(
+            Method::Get,
+            "/v2/householdlists/shop1/active",
+            200,
+            r#"{"listId": "shop1", "name": "Alexa shopping list", "state": "active", "version": 1,
+                "items": [{"id": "item1", "version": 1, "value": "milk", "status": "active"}]}"#,
+        );
+        mock.respond(
+            Method::Put,
+            "/v2/householdlists/shop1/items/item1",
+            200,
+            r#"{"id": "item1", "version": 2, "value": "milk", "status": "completed"}"#,
+        );
+        mock.respond(Method::Post, "/v2/householdlists/shop1/items", 403, "");
+        let req = RequestBuilder::launch().api_access_token("token").build();
+        let client = ListsClient::from_request(&req, &mock).unwrap();
+        assert_eq!(
+            client.shopping_list_id().unwrap(),
+            Some(String::from("shop1"))
+        );
+        assert_eq!(client.todo_list_id().unwrap(), Some(String::from("todo1")));
+
+        let list = client.list("shop1", ListStatus::Active).unwrap();
+        assert_eq!(list.items[0].value, "milk");
+        let item = client
+            .update_item("shop1", "item1", "milk", ListStatus::Completed, 1)
+            .unwrap();
+        assert_eq!(item.version, 2);
+        let sent = mock.last_request().unwrap();
+        let body: serde_json::Value = serde_json::from_str(&sent.body.unwrap()).unwrap();
+        assert_eq!(body["status"], "completed");
+        assert_eq!(body["version"], 1);
+
+        let card = client
+            .create_item("shop1", "eggs")
+            .unwrap_err()
+            .permission_card()
+            .unwrap();
+        assert_eq!(card.permissions(), Some(&[String::from(WRITE_SCOPE)][..]));
+    }
+}
@@ -0,0 +1,73 @@
+//! In-process fake of the Alexa service APIs, for testing skills that call them
+
+use super::{Error, HttpRequest, HttpResponse, Method, Transport};
+use std::cell::RefCell;
+
+/// A `Transport` returning canned responses and recording the requests sent to it
+/// requests with no canned response get a 404
+#[derive(Default)]
+pub struct MockTransport {
+    responses: RefCell<Vec<(Method, String, HttpResponse)>>,
+    requests: RefCell<Vec<HttpRequest>>,
+}
+
+impl MockTransport {
+    /// Constructs a transport with no canned responses
+    pub fn new() -> MockTransport {
+        MockTransport::default()
+    }
+
+    /// adds a canned response for requests with the given method and path,
+    /// including any query string, e.g. `/v1/devices/abc/settings/address`
+    /// the most recently added matching response is used
+    pub fn respond(&self, method: Method, path: &str, status: u16, body: &str) {
+        self.responses.borrow_mut().push((
+            method,
+            String::from(path),
+            HttpResponse {
+                status,
+                body: String::from(body),
+            },
+        ));
+    }
+
+    /// returns the requests sent so far
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.borrow().clone()
+    }
+
+    /// returns the most recent request sent, if any
+    pub fn last_request(&self) -> Option<HttpRequest> {
+        self.requests.borrow().last().cloned()
+    }
+}
+
+/// returns the path and query of a URL
+fn path(url: &str) -> &str {
+    let rest = match url.find("://") {
+        Some(i) => &url[i + 3..],
+        None => url,
+    };
+    match rest.find('/') {
+        Some(i) => &rest[i..],
+        None => "/",
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, req: &HttpRequest) -> Result<HttpResponse, Error> {
+        self.requests.borrow_mut().push(req.clone());
+        let p = path(&req.url);
+        let res = self
+            .responses
+            .borrow()
+            .iter()
+            .rev()
+            .find(|(m, rp, _)| *m == req.method && rp == p)
+            .map(|(_, _, r)| r.clone());
+        Ok(res.unwrap_or(HttpResponse {
+            status: 404,
+            body: String::new(),
+        }))
+    }
+}
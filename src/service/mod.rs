@@ -0,0 +1,388 @@
+//! Clients for the [Alexa service APIs](https://developer.amazon.com/docs/custom-skills/call-alexa-service-apis-from-a-custom-skill.html)
+//!
+//! A `ServiceClient` is created from a `Request`, which supplies the API endpoint
+//! and the bearer token for the current user and device. HTTP is delegated to a
+//! `Transport`, so skills can use the HTTP client of their choice, and tests can
+//! substitute `mock::MockTransport` for Alexa. With the `ureq` feature,
+//! `UreqTransport` is provided.
+
+extern crate serde;
+extern crate serde_json;
+
+use self::serde::de::DeserializeOwned;
+use self::serde::Serialize;
+use crate::request::Request;
+use crate::response::Card;
+use std::error;
+use std::fmt;
+
+pub mod address;
+pub mod lists;
+pub mod mock;
+pub mod monetization;
+pub mod proactive;
+pub mod profile;
+pub mod progressive;
+pub mod reminders;
+pub mod settings;
+pub mod timers;
+
+/// HTTP methods used by the Alexa service APIs
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// An HTTP request to be sent by a `Transport`
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    /// returns the value of the named header, if it exists
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// An HTTP response returned by a `Transport`
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends HTTP requests on behalf of the service clients
+pub trait Transport {
+    /// sends the request, returning the response for any HTTP status
+    /// errors are only for failures to get a response at all
+    fn send(&self, req: &HttpRequest) -> Result<HttpResponse, Error>;
+}
+
+impl<T: Transport + ?Sized> Transport for &T {
+    fn send(&self, req: &HttpRequest) -> Result<HttpResponse, Error> {
+        (**self).send(req)
+    }
+}
+
+/// Errors calling Alexa service APIs
+#[derive(Debug)]
+pub enum Error {
+    /// the request has no API access token
+    MissingToken,
+    /// the request has no API endpoint
+    MissingEndpoint,
+    /// the request has no device ID
+    MissingDevice,
+    /// the request has no recognized speaker
+    MissingPerson,
+    /// 401: the access token is invalid or expired
+    Unauthorized(String),
+    /// 403: the API refused the request
+    Forbidden(String),
+    /// 403: the user has not granted the permissions, by scope, that the API requires
+    PermissionRequired(Vec<String>),
+    /// 429: too many requests
+    TooManyRequests(String),
+    /// 5xx: the service failed
+    ServerError(u16, String),
+    /// any other unsuccessful status
+    Status(u16, String),
+    /// the transport could not get a response
+    Transport(String),
+    /// the response could not be parsed, or the request could not be serialized
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::MissingToken => write!(f, "request has no API access token"),
+            Error::MissingEndpoint => write!(f, "request has no API endpoint"),
+            Error::MissingDevice => write!(f, "request has no device ID"),
+            Error::MissingPerson => write!(f, "request has no recognized speaker"),
+            Error::Unauthorized(ref b) => write!(f, "unauthorized: {}", b),
+            Error::Forbidden(ref b) => write!(f, "forbidden: {}", b),
+            Error::PermissionRequired(ref scopes) => {
+                write!(f, "permission required: {}", scopes.join(", "))
+            }
+            Error::TooManyRequests(ref b) => write!(f, "too many requests: {}", b),
+            Error::ServerError(s, ref b) => write!(f, "server error {}: {}", s, b),
+            Error::Status(s, ref b) => write!(f, "unexpected status {}: {}", s, b),
+            Error::Transport(ref e) => write!(f, "transport error: {}", e),
+            Error::Json(ref e) => write!(f, "JSON error: {}", e),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Error {
+        Error::Json(e)
+    }
+}
+
+impl Error {
+    /// Constructs the error for an unsuccessful HTTP status
+    pub fn from_status(status: u16, body: String) -> Error {
+        match status {
+            401 => Error::Unauthorized(body),
+            403 => Error::Forbidden(body),
+            429 => Error::TooManyRequests(body),
+            500..=599 => Error::ServerError(status, body),
+            _ => Error::Status(status, body),
+        }
+    }
+
+    /// treats a 403 as the user not having granted the given permission scopes
+    pub fn require_permissions(self, scopes: &[&str]) -> Error {
+        match self {
+            Error::Forbidden(_) => {
+                Error::PermissionRequired(scopes.iter().map(|s| String::from(*s)).collect())
+            }
+            e => e,
+        }
+    }
+
+    /// returns a card asking the user for the missing permissions, if that is the error
+    pub fn permission_card(&self) -> Option<Card> {
+        match *self {
+            Error::PermissionRequired(ref scopes) => Some(Card::ask_for_permission(scopes.clone())),
+            _ => None,
+        }
+    }
+}
+
+/// returns the device ID of a request
+pub(crate) fn device_id(req: &Request) -> Result<&str, Error> {
+    req.context
+        .system
+        .device
+        .as_ref()
+        .map(|d| d.device_id.as_str())
+        .ok_or(Error::MissingDevice)
+}
+
+/// percent-encodes a query or form value
+pub(crate) fn url_encode(s: &str) -> String {
+    let mut out = String::new();
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+/// Client for the Alexa service APIs, authenticated for the user and device of a request
+pub struct ServiceClient<T> {
+    endpoint: String,
+    token: String,
+    headers: Vec<(String, String)>,
+    transport: T,
+}
+
+impl<T: Transport> ServiceClient<T> {
+    /// Constructs a client for the given API endpoint and access token
+    pub fn new(endpoint: &str, token: &str, transport: T) -> ServiceClient<T> {
+        ServiceClient {
+            endpoint: String::from(endpoint.trim_end_matches('/')),
+            token: String::from(token),
+            headers: Vec::new(),
+            transport,
+        }
+    }
+
+    /// adds a header sent with every request, e.g. `Accept-Language`
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((String::from(name), String::from(value)));
+        self
+    }
+
+    /// Constructs a client using the API endpoint and access token of a request
+    pub fn from_request(req: &Request, transport: T) -> Result<ServiceClient<T>, Error> {
+        let system = &req.context.system;
+        let endpoint = system.api_endpoint.as_ref().ok_or(Error::MissingEndpoint)?;
+        let token = system
+            .api_access_token
+            .as_ref()
+            .ok_or(Error::MissingToken)?;
+        Ok(ServiceClient::new(endpoint, token, transport))
+    }
+
+    /// returns the API endpoint
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// sends a request to the API path, returning the body of a successful response
+    pub fn send(&self, method: Method, path: &str, body: Option<String>) -> Result<String, Error> {
+        let mut headers = vec![
+            (
+                String::from("Authorization"),
+                format!("Bearer {}", self.token),
+            ),
+            (String::from("Accept"), String::from("application/json")),
+        ];
+        if body.is_some() {
+            headers.push((
+                String::from("Content-Type"),
+                String::from("application/json"),
+            ));
+        }
+        headers.extend(self.headers.iter().cloned());
+        let req = HttpRequest {
+            method,
+            url: format!("{}{}", self.endpoint, path),
+            headers,
+            body,
+        };
+        let res = self.transport.send(&req)?;
+        if (200..300).contains(&res.status) {
+            Ok(res.body)
+        } else {
+            Err(Error::from_status(res.status, res.body))
+        }
+    }
+
+    /// sends a GET request, parsing the JSON response
+    pub fn get<R: DeserializeOwned>(&self, path: &str) -> Result<R, Error> {
+        parse(&self.send(Method::Get, path, None)?)
+    }
+
+    /// sends a POST request with a JSON body, parsing the JSON response
+    pub fn post<B: Serialize, R: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<R, Error> {
+        let body = serde_json::to_string(body)?;
+        parse(&self.send(Method::Post, path, Some(body))?)
+    }
+
+    /// sends a PUT request with a JSON body, parsing the JSON response
+    pub fn put<B: Serialize, R: DeserializeOwned>(&self, path: &str, body: &B) -> Result<R, Error> {
+        let body = serde_json::to_string(body)?;
+        parse(&self.send(Method::Put, path, Some(body))?)
+    }
+
+    /// sends a DELETE request
+    pub fn delete(&self, path: &str) -> Result<(), Error> {
+        self.send(Method::Delete, path, None).map(|_| ())
+    }
+}
+
+/// parses a JSON response body, treating an empty body as `null`
+fn parse<R: DeserializeOwned>(body: &str) -> Result<R, Error> {
+    let body = if body.trim().is_empty() { "null" } else { body };
+    Ok(serde_json::from_str(body)?)
+}
+
+/// `Transport` implemented with the `ureq` HTTP client
+#[cfg(feature = "ureq")]
+#[derive(Default)]
+pub struct UreqTransport;
+
+#[cfg(feature = "ureq")]
+impl Transport for UreqTransport {
+    fn send(&self, req: &HttpRequest) -> Result<HttpResponse, Error> {
+        let mut r = ureq::request(&req.method.to_string(), &req.url);
+        for (k, v) in req.headers.iter() {
+            r = r.set(k, v);
+        }
+        let result = match req.body {
+            Some(ref b) => r.send_string(b),
+            None => r.call(),
+        };
+        let res = match result {
+            Ok(res) => res,
+            Err(ureq::Error::Status(_, res)) => res,
+            Err(e) => return Err(Error::Transport(e.to_string())),
+        };
+        let status = res.status();
+        let body = res
+            .into_string()
+            .map_err(|e| Error::Transport(e.to_string()))?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::MockTransport;
+    use super::*;
+    use crate::request_builder::RequestBuilder;
+
+    #[test]
+    fn test_from_request() {
+        let mock = MockTransport::new();
+        mock.respond(Method::Get, "/v1/ping", 200, r#"{"ok":true}"#);
+        let req = RequestBuilder::launch()
+            .api_endpoint("https://api.eu.amazonalexa.com/")
+            .api_access_token("token")
+            .build();
+        let client = ServiceClient::from_request(&req, &mock).unwrap();
+        let v: serde_json::Value = client.get("/v1/ping").unwrap();
+        assert_eq!(v["ok"], true);
+        let sent = &mock.requests()[0];
+        assert_eq!(sent.url, "https://api.eu.amazonalexa.com/v1/ping");
+        assert_eq!(sent.header("authorization"), Some("Bearer token"));
+
+        let req = RequestBuilder::launch().build();
+        match ServiceClient::from_request(&req, &mock) {
+            Err(Error::MissingToken) => {}
+            _ => panic!("expected a missing token error"),
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let mock = MockTransport::new();
+        mock.respond(Method::Get, "/a", 401, "");
+        mock.respond(Method::Get, "/b", 403, "");
+        mock.respond(Method::Get, "/c", 429, "");
+        mock.respond(Method::Get, "/d", 503, "");
+        mock.respond(Method::Delete, "/e", 204, "");
+        let client = ServiceClient::new("https://api.amazonalexa.com", "token", &mock);
+        let get = |p| client.get::<serde_json::Value>(p);
+        assert!(matches!(get("/a"), Err(Error::Unauthorized(_))));
+        assert!(matches!(get("/b"), Err(Error::Forbidden(_))));
+        assert!(matches!(get("/c"), Err(Error::TooManyRequests(_))));
+        assert!(matches!(get("/d"), Err(Error::ServerError(503, _))));
+        assert!(matches!(get("/f"), Err(Error::Status(404, _))));
+        assert!(client.delete("/e").is_ok());
+    }
+
+    #[test]
+    fn test_url_encode() {
+        assert_eq!(url_encode("a-Z_0.9~"), "a-Z_0.9~");
+        assert_eq!(url_encode("a+b/c=d e&"), "a%2Bb%2Fc%3Dd%20e%26");
+        assert_eq!(url_encode("é"), "%C3%A9");
+    }
+}
@@ -0,0 +1,240 @@
+//! Client for the [Monetization Service API](https://developer.amazon.com/docs/in-skill-purchase/in-skill-product-service.html)
+//!
+//! Purchases are started with the `Directive::buy`, `Directive::upsell` and
+//! `Directive::cancel` directives; their outcome is sent to the skill as a
+//! `Connections.Response` request, see `Request::purchase_result`.
+//!
+//! ```rust,no_run
+//! # use alexa_sdk::response::{Directive, Response};
+//! # use alexa_sdk::service::monetization::MonetizationClient;
+//! # use alexa_sdk::request_builder::RequestBuilder;
+//! # use alexa_sdk::service::mock::MockTransport;
+//! # fn main() -> Result<(), alexa_sdk::service::Error> {
+//! # let req = RequestBuilder::launch().api_access_token("token").build();
+//! # let transport = MockTransport::new();
+//! let products = MonetizationClient::from_request(&req, transport)?.products()?;
+//! let res = match products.iter().find(|p| p.reference_name == "premium") {
+//!     Some(p) if !p.is_entitled() && p.is_purchasable() => Response::new(false)
+//!         .directive(Directive::upsell(&p.product_id, "Want premium?", "premium")),
+//!     _ => Response::end(),
+//! };
+//! # let _ = res;
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate serde_derive;
+
+use self::serde_derive::{Deserialize, Serialize};
+use super::{url_encode, Error, ServiceClient, Transport};
+use crate::request::Request;
+
+/// Types of in-skill products
+#[derive(Debug, PartialEq)]
+pub enum ProductType {
+    Subscription,
+    Entitlement,
+    Consumable,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for ProductType {
+    fn from(s: &'a str) -> ProductType {
+        match s {
+            "SUBSCRIPTION" => ProductType::Subscription,
+            "ENTITLEMENT" => ProductType::Entitlement,
+            "CONSUMABLE" => ProductType::Consumable,
+            _ => ProductType::Other(s.to_string()),
+        }
+    }
+}
+
+/// An in-skill product, with its entitlement and purchasable status for the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InSkillProduct {
+    pub product_id: String,
+    pub reference_name: String,
+    #[serde(rename = "type")]
+    pub product_type: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// `ENTITLED` or `NOT_ENTITLED`
+    pub entitled: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entitlement_reason: Option<String>,
+    /// `PURCHASABLE` or `NOT_PURCHASABLE`
+    pub purchasable: String,
+    #[serde(default)]
+    pub active_entitlement_count: u32,
+    /// `TEST` or `LIVE`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purchase_mode: Option<String>,
+}
+
+impl InSkillProduct {
+    /// Extracts the product type
+    pub fn product_type(&self) -> ProductType {
+        ProductType::from(self.product_type.as_str())
+    }
+
+    /// returns whether the user is entitled to the product
+    pub fn is_entitled(&self) -> bool {
+        self.entitled == "ENTITLED"
+    }
+
+    /// returns whether the user can buy the product
+    pub fn is_purchasable(&self) -> bool {
+        self.purchasable == "PURCHASABLE"
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct ProductPage {
+    #[serde(default)]
+    in_skill_products: Vec<InSkillProduct>,
+    next_token: Option<String>,
+}
+
+/// Client for the skill's in-skill products, as seen by the user of a request
+pub struct MonetizationClient<T> {
+    client: ServiceClient<T>,
+}
+
+impl<T: Transport> MonetizationClient<T> {
+    /// Constructs a client using the given service client
+    pub fn new(client: ServiceClient<T>) -> MonetizationClient<T> {
+        MonetizationClient { client }
+    }
+
+    /// Constructs a client for the API endpoint, access token and locale of a request
+    /// product names and summaries are in the locale of the request
+    pub fn from_request(req: &Request, transport: T) -> Result<MonetizationClient<T>, Error> {
+        let client = ServiceClient::from_request(req, transport)?
+            .header("Accept-Language", &req.body.locale);
+        Ok(MonetizationClient::new(client))
+    }
+
+    /// retrieves all of the skill's in-skill products
+    pub fn products(&self) -> Result<Vec<InSkillProduct>, Error> {
+        self.products_where("")
+    }
+
+    /// retrieves the in-skill products the user is entitled to
+    pub fn entitled_products(&self) -> Result<Vec<InSkillProduct>, Error> {
+        self.products_where("entitled=ENTITLED")
+    }
+
+    /// retrieves the in-skill products the user can buy
+    pub fn purchasable_products(&self) -> Result<Vec<InSkillProduct>, Error> {
+        self.products_where("purchasable=PURCHASABLE")
+    }
+
+    /// retrieves an in-skill product
+    pub fn product(&self, product_id: &str) -> Result<InSkillProduct, Error> {
+        self.client.get(&format!(
+            "/v1/users/~current/skills/~current/inSkillProducts/{}",
+            product_id
+        ))
+    }
+
+    fn products_where(&self, query: &str) -> Result<Vec<InSkillProduct>, Error> {
+        let mut products = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let mut params: Vec<String> = Vec::new();
+            if !query.is_empty() {
+                params.push(String::from(query));
+            }
+            if let Some(ref t) = next_token {
+                params.push(format!("nextToken={}", url_encode(t)));
+            }
+            let mut path = String::from("/v1/users/~current/skills/~current/inSkillProducts");
+            if !params.is_empty() {
+                path.push('?');
+                path.push_str(&params.join("&"));
+            }
+            let page: ProductPage = self.client.get(&path)?;
+            products.extend(page.in_skill_products);
+            match page.next_token {
+                Some(t) if !t.is_empty() => next_token = Some(t),
+                _ => return Ok(products),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockTransport;
+    use super::super::Method;
+    use super::*;
+    use crate::request::Locale;
+    use crate::request_builder::RequestBuilder;
+
+    fn product(id: &str, entitled: &str) -> String {
+        format!(
+            r#"{{
+                "productId": "{}",
+                "referenceName": "premium",
+                "type": "SUBSCRIPTION",
+                "name": "Premium",
+                "summary": "All the things",
+                "entitled": "{}",
+                "entitlementReason": "PURCHASED",
+                "purchasable": "NOT_PURCHASABLE",
+                "activeEntitlementCount": 1,
+                "purchaseMode": "TEST"
+            }}"#,
+            id, entitled
+        )
+    }
+
+    #[test]
+    fn test_products() {
+        let mock = MockTransport::new();
+        let path = "/v1/users/~current/skills/~current/inSkillProducts";
+        mock.respond(
+            Method::Get,
+            path,
+            200,
+            &format!(
+                r#"{{"inSkillProducts": [{}], "nextToken": "page+2/a==", "truncated": true}}"#,
+                product("p1", "ENTITLED")
+            ),
+        );
+        mock.respond(
+            Method::Get,
+            &format!("{}?nextToken=page%2B2%2Fa%3D%3D", path),
+            200,
+            &format!(
+                r#"{{"inSkillProducts": [{}], "nextToken": null, "truncated": false}}"#,
+                product("p2", "NOT_ENTITLED")
+            ),
+        );
+        mock.respond(
+            Method::Get,
+            &format!("{}/p1", path),
+            200,
+            &product("p1", "ENTITLED"),
+        );
+        let req = RequestBuilder::launch()
+            .locale(Locale::German)
+            .api_access_token("token")
+            .build();
+        let client = MonetizationClient::from_request(&req, &mock).unwrap();
+        let products = client.products().unwrap();
+        assert_eq!(products.len(), 2);
+        assert!(products[0].is_entitled());
+        assert!(!products[1].is_entitled());
+        assert!(!products[1].is_purchasable());
+        assert_eq!(products[0].product_type(), ProductType::Subscription);
+
+        let p = client.product("p1").unwrap();
+        assert_eq!(p.reference_name, "premium");
+        let sent = mock.last_request().unwrap();
+        assert_eq!(sent.header("Accept-Language"), Some("de-DE"));
+    }
+}
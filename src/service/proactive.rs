@@ -0,0 +1,521 @@
+//! Client for the [Proactive Events API](https://developer.amazon.com/docs/smapi/proactive-events-api.html)
+//!
+//! Proactive events are sent outside of any request, so the client authenticates
+//! with the skill's Login with Amazon client ID and secret rather than a request
+//! token:
+//!
+//! ```rust,no_run
+//! # use alexa_sdk::service::proactive::{Audience, Event, MessageAlert, ProactiveEvent, ProactiveEventsClient, Stage};
+//! # use std::time::Duration;
+//! # use alexa_sdk::request_builder::RequestBuilder;
+//! # use alexa_sdk::service::mock::MockTransport;
+//! # fn main() -> Result<(), alexa_sdk::service::Error> {
+//! # let req = RequestBuilder::launch().api_access_token("token").build();
+//! # let transport = MockTransport::new();
+//! # let user_id = "amzn1.ask.account.test";
+//! let client = ProactiveEventsClient::new("amzn1.application-oa2-client.xxx", "secret", transport)
+//!     .stage(Stage::Live);
+//! let event = Event::MessageAlert(MessageAlert::new("Andy", 5));
+//! client.send(&ProactiveEvent::new(event, Audience::unicast(user_id), Duration::from_secs(3600)))?;
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate serde_derive;
+extern crate serde_json;
+
+use self::serde_derive::{Deserialize, Serialize};
+use super::{url_encode, Error, HttpRequest, Method, ServiceClient, Transport};
+use crate::util::{generate_id, timestamp_at};
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Login with Amazon token endpoint
+pub const TOKEN_URL: &str = "https://api.amazon.com/auth/o2/token";
+/// API endpoint for North America
+pub const ENDPOINT_NA: &str = "https://api.amazonalexa.com";
+/// API endpoint for Europe and India
+pub const ENDPOINT_EU: &str = "https://api.eu.amazonalexa.com";
+/// API endpoint for the Far East
+pub const ENDPOINT_FE: &str = "https://api.fe.amazonalexa.com";
+
+/// Stage of the skill the events are sent to
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Stage {
+    Development,
+    Live,
+}
+
+impl Stage {
+    fn path(self) -> &'static str {
+        match self {
+            Stage::Development => "/v1/proactiveEvents/stages/development",
+            Stage::Live => "/v1/proactiveEvents",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MessageStatus {
+    Unread,
+    Flagged,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MessageFreshness {
+    New,
+    Overdue,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessageState {
+    pub status: MessageStatus,
+    pub freshness: MessageFreshness,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Creator {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessageGroup {
+    pub creator: Creator,
+    pub count: u32,
+    /// `URGENT`, if set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub urgency: Option<String>,
+}
+
+/// Payload of `AMAZON.MessageAlert.Activated`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageAlert {
+    pub state: MessageState,
+    pub message_group: MessageGroup,
+}
+
+impl MessageAlert {
+    /// Constructs an alert for new unread messages from the creator
+    pub fn new(creator: &str, count: u32) -> MessageAlert {
+        MessageAlert {
+            state: MessageState {
+                status: MessageStatus::Unread,
+                freshness: MessageFreshness::New,
+            },
+            message_group: MessageGroup {
+                creator: Creator {
+                    name: String::from(creator),
+                },
+                count,
+                urgency: None,
+            },
+        }
+    }
+
+    /// marks the messages as urgent
+    pub fn urgent(mut self) -> Self {
+        self.message_group.urgency = Some(String::from("URGENT"));
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatus {
+    PreorderReceived,
+    OrderReceived,
+    OrderPreparing,
+    OrderShipped,
+    OrderOutForDelivery,
+    OrderDelivered,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryDetails {
+    /// ISO 8601 time, e.g. `2018-12-14T23:32:00.463Z`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_arrival: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_time: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderState {
+    pub status: OrderStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_details: Option<DeliveryDetails>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Seller {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Order {
+    pub seller: Seller,
+}
+
+/// Payload of `AMAZON.OrderStatus.Updated`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OrderUpdate {
+    pub state: OrderState,
+    pub order: Order,
+}
+
+impl OrderUpdate {
+    /// Constructs an update of an order from the seller
+    /// the seller name may refer to a localized attribute, e.g. `localizedattribute:sellerName`
+    pub fn new(status: OrderStatus, seller: &str) -> OrderUpdate {
+        OrderUpdate {
+            state: OrderState {
+                status,
+                delivery_details: None,
+            },
+            order: Order {
+                seller: Seller {
+                    name: String::from(seller),
+                },
+            },
+        }
+    }
+
+    /// sets the expected arrival time
+    pub fn expected_arrival(mut self, time: &str) -> Self {
+        let details = self.state.delivery_details.get_or_insert(DeliveryDetails {
+            expected_arrival: None,
+            delivery_time: None,
+        });
+        details.expected_arrival = Some(String::from(time));
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WeatherAlertDetails {
+    pub source: String,
+    /// e.g. `SNOW_STORM`, `TORNADO`, `HURRICANE` or `DEFAULT`
+    pub alert_type: String,
+}
+
+/// Payload of `AMAZON.WeatherAlert.Activated`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WeatherAlert {
+    pub weather_alert: WeatherAlertDetails,
+}
+
+impl WeatherAlert {
+    /// Constructs an alert of the given type from the source
+    /// the source may refer to a localized attribute, e.g. `localizedattribute:source`
+    pub fn new(source: &str, alert_type: &str) -> WeatherAlert {
+        WeatherAlert {
+            weather_alert: WeatherAlertDetails {
+                source: String::from(source),
+                alert_type: String::from(alert_type),
+            },
+        }
+    }
+}
+
+/// Any other event schema
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CustomEvent {
+    pub name: String,
+    pub payload: serde_json::Value,
+}
+
+/// A proactive event, by schema
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "name", content = "payload")]
+pub enum Event {
+    #[serde(rename = "AMAZON.MessageAlert.Activated")]
+    MessageAlert(MessageAlert),
+    #[serde(rename = "AMAZON.OrderStatus.Updated")]
+    OrderStatus(OrderUpdate),
+    #[serde(rename = "AMAZON.WeatherAlert.Activated")]
+    WeatherAlert(WeatherAlert),
+    #[serde(untagged)]
+    Custom(CustomEvent),
+}
+
+/// Who receives an event
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", content = "payload")]
+pub enum Audience {
+    /// one user, by user ID
+    Unicast { user: String },
+    /// every user subscribed to the event
+    Multicast {},
+}
+
+impl Audience {
+    /// Constructs an audience of one user
+    pub fn unicast(user_id: &str) -> Audience {
+        Audience::Unicast {
+            user: String::from(user_id),
+        }
+    }
+
+    /// Constructs an audience of every subscribed user
+    pub fn multicast() -> Audience {
+        Audience::Multicast {}
+    }
+}
+
+/// An event to send
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProactiveEvent {
+    pub timestamp: String,
+    pub reference_id: String,
+    pub expiry_time: String,
+    pub event: Event,
+    pub localized_attributes: Vec<HashMap<String, String>>,
+    pub relevant_audience: Audience,
+}
+
+impl ProactiveEvent {
+    /// Constructs an event sent now, expiring after the given duration (at most 24 hours)
+    pub fn new(event: Event, audience: Audience, expires_in: Duration) -> ProactiveEvent {
+        let now = SystemTime::now();
+        ProactiveEvent {
+            timestamp: timestamp_at(now),
+            reference_id: generate_id(),
+            expiry_time: timestamp_at(now + expires_in),
+            event,
+            localized_attributes: Vec::new(),
+            relevant_audience: audience,
+        }
+    }
+
+    /// adds attributes for a locale, referred to in the payload as `localizedattribute:<key>`
+    pub fn localized(mut self, locale: &str, attributes: &[(&str, &str)]) -> Self {
+        let mut map = HashMap::new();
+        map.insert(String::from("locale"), String::from(locale));
+        for (k, v) in attributes {
+            map.insert(String::from(*k), String::from(*v));
+        }
+        self.localized_attributes.push(map);
+        self
+    }
+
+    /// sets the reference ID, which must be unique for the skill
+    pub fn reference_id(mut self, id: &str) -> Self {
+        self.reference_id = String::from(id);
+        self
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+/// Client sending proactive events for a skill, authenticated with its LWA credentials
+pub struct ProactiveEventsClient<T> {
+    client_id: String,
+    client_secret: String,
+    endpoint: String,
+    token_url: String,
+    stage: Stage,
+    transport: T,
+    token: Mutex<Option<(String, Instant)>>,
+}
+
+impl<T: Transport> ProactiveEventsClient<T> {
+    /// Constructs a client sending to the development stage in North America
+    pub fn new(client_id: &str, client_secret: &str, transport: T) -> ProactiveEventsClient<T> {
+        ProactiveEventsClient {
+            client_id: String::from(client_id),
+            client_secret: String::from(client_secret),
+            endpoint: String::from(ENDPOINT_NA),
+            token_url: String::from(TOKEN_URL),
+            stage: Stage::Development,
+            transport,
+            token: Mutex::new(None),
+        }
+    }
+
+    /// sets the API endpoint, e.g. `ENDPOINT_EU`
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = String::from(endpoint);
+        self
+    }
+
+    /// sets the URL access tokens are requested from
+    pub fn token_url(mut self, url: &str) -> Self {
+        self.token_url = String::from(url);
+        self
+    }
+
+    /// sets the stage of the skill the events are sent to
+    pub fn stage(mut self, stage: Stage) -> Self {
+        self.stage = stage;
+        self
+    }
+
+    /// retrieves an access token with the client credentials, reusing it until it expires
+    pub fn access_token(&self) -> Result<String, Error> {
+        if let Some((ref token, expires)) =
+            *self.token.lock().unwrap_or_else(PoisonError::into_inner)
+        {
+            if Instant::now() < expires {
+                return Ok(token.clone());
+            }
+        }
+        let body = format!(
+            "grant_type=client_credentials&client_id={}&client_secret={}&scope=alexa::proactive_events",
+            url_encode(&self.client_id),
+            url_encode(&self.client_secret)
+        );
+        let req = HttpRequest {
+            method: Method::Post,
+            url: self.token_url.clone(),
+            headers: vec![(
+                String::from("Content-Type"),
+                String::from("application/x-www-form-urlencoded"),
+            )],
+            body: Some(body),
+        };
+        let res = self.transport.send(&req)?;
+        if !(200..300).contains(&res.status) {
+            return Err(Error::from_status(res.status, res.body));
+        }
+        let token: TokenResponse = serde_json::from_str(&res.body)?;
+        // refresh a minute early so the token does not expire in flight
+        let expires = Instant::now() + Duration::from_secs(token.expires_in.saturating_sub(60));
+        *self.token.lock().unwrap_or_else(PoisonError::into_inner) =
+            Some((token.access_token.clone(), expires));
+        Ok(token.access_token)
+    }
+
+    /// sends an event
+    pub fn send(&self, event: &ProactiveEvent) -> Result<(), Error> {
+        let token = self.access_token()?;
+        let client = ServiceClient::new(&self.endpoint, &token, &self.transport);
+        let body = serde_json::to_string(event)?;
+        client
+            .send(Method::Post, self.stage.path(), Some(body))
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockTransport;
+    use super::*;
+
+    #[test]
+    fn test_event_json() {
+        let event = ProactiveEvent::new(
+            Event::OrderStatus(
+                OrderUpdate::new(OrderStatus::OrderShipped, "localizedattribute:sellerName")
+                    .expected_arrival("2018-12-14T23:32:00.463Z"),
+            ),
+            Audience::unicast("amzn1.ask.account.user"),
+            Duration::from_secs(3600),
+        )
+        .localized("en-US", &[("sellerName", "Delivery Co.")]);
+        let v = serde_json::to_value(&event).unwrap();
+        assert_eq!(v["event"]["name"], "AMAZON.OrderStatus.Updated");
+        assert_eq!(v["event"]["payload"]["state"]["status"], "ORDER_SHIPPED");
+        assert_eq!(
+            v["event"]["payload"]["state"]["deliveryDetails"]["expectedArrival"],
+            "2018-12-14T23:32:00.463Z"
+        );
+        assert_eq!(v["localizedAttributes"][0]["locale"], "en-US");
+        assert_eq!(v["localizedAttributes"][0]["sellerName"], "Delivery Co.");
+        assert_eq!(v["relevantAudience"]["type"], "Unicast");
+        assert_eq!(
+            v["relevantAudience"]["payload"]["user"],
+            "amzn1.ask.account.user"
+        );
+
+        let v = serde_json::to_value(Audience::multicast()).unwrap();
+        assert_eq!(v, serde_json::json!({"type": "Multicast", "payload": {}}));
+
+        let custom = Event::Custom(CustomEvent {
+            name: String::from("AMAZON.SportsEvent.Updated"),
+            payload: serde_json::json!({"update": {}}),
+        });
+        let v = serde_json::to_value(custom).unwrap();
+        assert_eq!(v["name"], "AMAZON.SportsEvent.Updated");
+    }
+
+    #[test]
+    fn test_send() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Method::Post,
+            "/auth/o2/token",
+            200,
+            r#"{"access_token": "lwa-token", "expires_in": 3600, "scope": "alexa::proactive_events", "token_type": "bearer"}"#,
+        );
+        mock.respond(Method::Post, "/v1/proactiveEvents", 202, "");
+        let client = ProactiveEventsClient::new("client id", "s3cret&", &mock)
+            .endpoint(ENDPOINT_EU)
+            .stage(Stage::Live);
+        let event = ProactiveEvent::new(
+            Event::MessageAlert(MessageAlert::new("Andy", 5).urgent()),
+            Audience::multicast(),
+            Duration::from_secs(3600),
+        );
+        client.send(&event).unwrap();
+        client.send(&event).unwrap();
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3, "the token should be reused");
+        let token_body = requests[0].body.as_ref().unwrap();
+        assert!(token_body.contains("client_id=client%20id"));
+        assert!(token_body.contains("client_secret=s3cret%26"));
+        assert_eq!(
+            requests[1].url,
+            "https://api.eu.amazonalexa.com/v1/proactiveEvents"
+        );
+        assert_eq!(
+            requests[1].header("Authorization"),
+            Some("Bearer lwa-token")
+        );
+        let body: serde_json::Value =
+            serde_json::from_str(requests[1].body.as_ref().unwrap()).unwrap();
+        assert_eq!(
+            body["event"]["payload"]["messageGroup"]["urgency"],
+            "URGENT"
+        );
+    }
+
+    #[test]
+    fn test_token_error() {
+        let mock = MockTransport::new();
+        mock.respond(Method::Post, "/auth/o2/token", 401, "invalid_client");
+        let client = ProactiveEventsClient::new("id", "secret", &mock);
+        let event = ProactiveEvent::new(
+            Event::WeatherAlert(WeatherAlert::new("localizedattribute:source", "SNOW_STORM")),
+            Audience::multicast(),
+            Duration::from_secs(3600),
+        );
+        assert!(matches!(client.send(&event), Err(Error::Unauthorized(_))));
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_sync() {
+        struct NoTransport;
+        impl Transport for NoTransport {
+            fn send(&self, _req: &HttpRequest) -> Result<super::super::HttpResponse, Error> {
+                Err(Error::Transport(String::from("unreachable")))
+            }
+        }
+        fn assert_sync<T: Sync>(_: &T) {}
+        assert_sync(&ProactiveEventsClient::new("id", "secret", NoTransport));
+    }
+}
@@ -0,0 +1,174 @@
+//! Clients for the [Customer Profile API](https://developer.amazon.com/docs/custom-skills/request-customer-contact-information-for-use-in-your-skill.html)
+//! and the [Person Profile API](https://developer.amazon.com/docs/custom-skills/request-recognized-speaker-contact-information.html)
+//!
+//! Calls fail with `Error::PermissionRequired` when the user has not granted the
+//! scope for the requested value; `Error::permission_card` turns that into a card
+//! asking for it.
+
+extern crate serde;
+extern crate serde_derive;
+
+use self::serde::de::DeserializeOwned;
+use self::serde_derive::{Deserialize, Serialize};
+use super::{Error, ServiceClient, Transport};
+use crate::request::Request;
+
+/// permission scope for the full name
+pub const NAME_SCOPE: &str = "alexa::profile:name:read";
+/// permission scope for the given name
+pub const GIVEN_NAME_SCOPE: &str = "alexa::profile:given_name:read";
+/// permission scope for the email address
+pub const EMAIL_SCOPE: &str = "alexa::profile:email:read";
+/// permission scope for the mobile number
+pub const MOBILE_NUMBER_SCOPE: &str = "alexa::profile:mobile_number:read";
+
+/// A phone number
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PhoneNumber {
+    pub country_code: Option<String>,
+    pub phone_number: Option<String>,
+}
+
+/// Client for the profile of the customer (the account holder) of a request
+pub struct CustomerProfileClient<T> {
+    client: ServiceClient<T>,
+}
+
+impl<T: Transport> CustomerProfileClient<T> {
+    /// Constructs a client using the given service client
+    pub fn new(client: ServiceClient<T>) -> CustomerProfileClient<T> {
+        CustomerProfileClient { client }
+    }
+
+    /// Constructs a client for the API endpoint and access token of a request
+    pub fn from_request(req: &Request, transport: T) -> Result<CustomerProfileClient<T>, Error> {
+        Ok(CustomerProfileClient::new(ServiceClient::from_request(
+            req, transport,
+        )?))
+    }
+
+    /// retrieves the customer's full name
+    pub fn name(&self) -> Result<String, Error> {
+        self.get("Profile.name", NAME_SCOPE)
+    }
+
+    /// retrieves the customer's given name
+    pub fn given_name(&self) -> Result<String, Error> {
+        self.get("Profile.givenName", GIVEN_NAME_SCOPE)
+    }
+
+    /// retrieves the customer's email address
+    pub fn email(&self) -> Result<String, Error> {
+        self.get("Profile.email", EMAIL_SCOPE)
+    }
+
+    /// retrieves the customer's mobile number
+    pub fn mobile_number(&self) -> Result<PhoneNumber, Error> {
+        self.get("Profile.mobileNumber", MOBILE_NUMBER_SCOPE)
+    }
+
+    fn get<R: DeserializeOwned>(&self, setting: &str, scope: &str) -> Result<R, Error> {
+        let path = format!("/v2/accounts/~current/settings/{}", setting);
+        self.client
+            .get(&path)
+            .map_err(|e| e.require_permissions(&[scope]))
+    }
+}
+
+/// Client for the profile of the recognized speaker of a request
+pub struct PersonProfileClient<T> {
+    client: ServiceClient<T>,
+}
+
+impl<T: Transport> PersonProfileClient<T> {
+    /// Constructs a client using the given service client
+    pub fn new(client: ServiceClient<T>) -> PersonProfileClient<T> {
+        PersonProfileClient { client }
+    }
+
+    /// Constructs a client for the API endpoint and access token of a request
+    /// fails with `Error::MissingPerson` if the speaker was not recognized
+    pub fn from_request(req: &Request, transport: T) -> Result<PersonProfileClient<T>, Error> {
+        if req.context.system.person.is_none() {
+            return Err(Error::MissingPerson);
+        }
+        Ok(PersonProfileClient::new(ServiceClient::from_request(
+            req, transport,
+        )?))
+    }
+
+    /// retrieves the speaker's full name
+    pub fn name(&self) -> Result<String, Error> {
+        self.get("name", NAME_SCOPE)
+    }
+
+    /// retrieves the speaker's given name
+    pub fn given_name(&self) -> Result<String, Error> {
+        self.get("givenName", GIVEN_NAME_SCOPE)
+    }
+
+    /// retrieves the speaker's mobile number
+    pub fn mobile_number(&self) -> Result<PhoneNumber, Error> {
+        self.get("mobileNumber", MOBILE_NUMBER_SCOPE)
+    }
+
+    fn get<R: DeserializeOwned>(&self, field: &str, scope: &str) -> Result<R, Error> {
+        let path = format!("/v2/persons/~current/profile/{}", field);
+        self.client
+            .get(&path)
+            .map_err(|e| e.require_permissions(&[scope]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockTransport;
+    use super::super::Method;
+    use super::*;
+    use crate::request_builder::RequestBuilder;
+
+    #[test]
+    fn test_customer_profile() {
+        let mock = MockTransport::new();
+        let path = "/v2/accounts/~current/settings/Profile.";
+        mock.respond(Method::Get, &format!("{}name", path), 200, r#""Jane Doe""#);
+        mock.respond(
+            Method::Get,
+            &format!("{}mobileNumber", path),
+            200,
+            r#"{"countryCode": "+1", "phoneNumber": "555-0100"}"#,
+        );
+        mock.respond(Method::Get, &format!("{}email", path), 403, "");
+        let req = RequestBuilder::launch().api_access_token("token").build();
+        let client = CustomerProfileClient::from_request(&req, &mock).unwrap();
+        assert_eq!(client.name().unwrap(), "Jane Doe");
+        let number = client.mobile_number().unwrap();
+        assert_eq!(number.country_code, Some(String::from("+1")));
+        assert_eq!(number.phone_number, Some(String::from("555-0100")));
+        let card = client.email().unwrap_err().permission_card().unwrap();
+        assert_eq!(card.permissions(), Some(&[String::from(EMAIL_SCOPE)][..]));
+    }
+
+    #[test]
+    fn test_person_profile() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Method::Get,
+            "/v2/persons/~current/profile/givenName",
+            200,
+            r#""Jane""#,
+        );
+        let req = RequestBuilder::launch().api_access_token("token").build();
+        assert!(matches!(
+            PersonProfileClient::from_request(&req, &mock),
+            Err(Error::MissingPerson)
+        ));
+        let req = RequestBuilder::launch()
+            .api_access_token("token")
+            .person_id("person1")
+            .build();
+        let client = PersonProfileClient::from_request(&req, &mock).unwrap();
+        assert_eq!(client.given_name().unwrap(), "Jane");
+    }
+}
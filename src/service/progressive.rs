@@ -0,0 +1,116 @@
+//! Client for the [Progressive Response API](https://developer.amazon.com/docs/custom-skills/send-the-user-a-progressive-response.html)
+//!
+//! Progressive responses are spoken while the skill is still working on its final
+//! response, e.g. before calling a slow backend:
+//!
+//! ```rust,no_run
+//! # use alexa_sdk::response::Speech;
+//! # use alexa_sdk::service::progressive::ProgressiveResponseClient;
+//! # use alexa_sdk::request_builder::RequestBuilder;
+//! # use alexa_sdk::service::mock::MockTransport;
+//! # fn main() -> Result<(), alexa_sdk::service::Error> {
+//! # let req = RequestBuilder::launch().api_access_token("token").build();
+//! # let transport = MockTransport::new();
+//! # fn slow_lookup() {}
+//! let progressive = ProgressiveResponseClient::from_request(&req, transport)?;
+//! progressive.speak(&Speech::plain("Looking that up"))?;
+//! let result = slow_lookup();
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate serde_derive;
+
+use self::serde_derive::Serialize;
+use super::{Error, Method, ServiceClient, Transport};
+use crate::request::Request;
+use crate::response::Speech;
+
+#[derive(Serialize, Debug)]
+struct DirectiveRequest<'a> {
+    header: Header<'a>,
+    directive: SpeakDirective<'a>,
+}
+
+#[derive(Serialize, Debug)]
+struct Header<'a> {
+    #[serde(rename = "requestId")]
+    request_id: &'a str,
+}
+
+#[derive(Serialize, Debug)]
+struct SpeakDirective<'a> {
+    #[serde(rename = "type")]
+    directive_type: &'a str,
+    speech: &'a str,
+}
+
+/// Client for sending progressive responses to the request being handled
+pub struct ProgressiveResponseClient<T> {
+    client: ServiceClient<T>,
+    request_id: String,
+}
+
+impl<T: Transport> ProgressiveResponseClient<T> {
+    /// Constructs a client for progressive responses to the given request ID
+    pub fn new(client: ServiceClient<T>, request_id: &str) -> ProgressiveResponseClient<T> {
+        ProgressiveResponseClient {
+            client,
+            request_id: String::from(request_id),
+        }
+    }
+
+    /// Constructs a client for progressive responses to a request
+    pub fn from_request(
+        req: &Request,
+        transport: T,
+    ) -> Result<ProgressiveResponseClient<T>, Error> {
+        Ok(ProgressiveResponseClient::new(
+            ServiceClient::from_request(req, transport)?,
+            &req.body.request_id,
+        ))
+    }
+
+    /// speaks plain text or SSML speech to the user with a `VoicePlayer.Speak` directive
+    pub fn speak(&self, speech: &Speech) -> Result<(), Error> {
+        let body = DirectiveRequest {
+            header: Header {
+                request_id: &self.request_id,
+            },
+            directive: SpeakDirective {
+                directive_type: "VoicePlayer.Speak",
+                speech: speech.content().unwrap_or(""),
+            },
+        };
+        let body = serde_json::to_string(&body)?;
+        self.client
+            .send(Method::Post, "/v1/directives", Some(body))
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockTransport;
+    use super::*;
+    use crate::request_builder::RequestBuilder;
+
+    #[test]
+    fn test_speak() {
+        let mock = MockTransport::new();
+        mock.respond(Method::Post, "/v1/directives", 204, "");
+        let req = RequestBuilder::launch()
+            .request_id("req1")
+            .api_access_token("token")
+            .build();
+        let client = ProgressiveResponseClient::from_request(&req, &mock).unwrap();
+        client
+            .speak(&Speech::ssml("<speak>One moment</speak>"))
+            .unwrap();
+        let sent = mock.last_request().unwrap();
+        let body: serde_json::Value = serde_json::from_str(&sent.body.unwrap()).unwrap();
+        assert_eq!(body["header"]["requestId"], "req1");
+        assert_eq!(body["directive"]["type"], "VoicePlayer.Speak");
+        assert_eq!(body["directive"]["speech"], "<speak>One moment</speak>");
+    }
+}
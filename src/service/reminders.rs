@@ -0,0 +1,423 @@
+//! Client for the [Reminders API](https://developer.amazon.com/docs/smapi/alexa-reminders-api-reference.html)
+//!
+//! ```rust,no_run
+//! # use alexa_sdk::service::reminders::{Recurrence, ReminderRequest, RemindersClient};
+//! # use alexa_sdk::request_builder::RequestBuilder;
+//! # use alexa_sdk::service::mock::MockTransport;
+//! # fn main() -> Result<(), alexa_sdk::service::Error> {
+//! # let req = RequestBuilder::launch().api_access_token("token").build();
+//! # let transport = MockTransport::new();
+//! let reminder = ReminderRequest::absolute("2019-09-22T19:00:00.000", "America/Los_Angeles")
+//!     .recurrence(Recurrence::rules(vec!["FREQ=DAILY;BYHOUR=19;BYMINUTE=0"]))
+//!     .content("en-US", "walk the dog");
+//! let created = RemindersClient::from_request(&req, transport)?.create(&reminder)?;
+//! # let _ = created;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Changes to the skill's reminders are sent to it as `Reminders.*` requests; see
+//! `Request::reminder_event`.
+
+extern crate serde_derive;
+
+use self::serde_derive::{Deserialize, Serialize};
+use super::{Error, ServiceClient, Transport};
+use crate::request::Request;
+use std::fmt;
+
+/// permission scope for reading and writing the skill's reminders
+pub const REMINDERS_SCOPE: &str = "alexa::alerts:reminders:skill:readwrite";
+
+/// Types of reminder triggers
+#[derive(Debug, PartialEq)]
+pub enum TriggerType {
+    ScheduledAbsolute,
+    ScheduledRelative,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for TriggerType {
+    fn from(s: &'a str) -> TriggerType {
+        match s {
+            "SCHEDULED_ABSOLUTE" => TriggerType::ScheduledAbsolute,
+            "SCHEDULED_RELATIVE" => TriggerType::ScheduledRelative,
+            _ => TriggerType::Other(s.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for TriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            TriggerType::ScheduledAbsolute => "SCHEDULED_ABSOLUTE",
+            TriggerType::ScheduledRelative => "SCHEDULED_RELATIVE",
+            TriggerType::Other(ref s) => s,
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// When a reminder goes off
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trigger {
+    #[serde(rename = "type")]
+    pub trigger_type: String,
+    /// local time without offset, for absolute triggers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_time: Option<String>,
+    /// seconds from the request, for relative triggers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_in_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<Recurrence>,
+}
+
+impl Trigger {
+    /// Extracts the trigger type
+    pub fn trigger_type(&self) -> TriggerType {
+        TriggerType::from(self.trigger_type.as_str())
+    }
+}
+
+/// How a reminder repeats
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Recurrence {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<String>,
+    /// RFC 5545 rules, e.g. `FREQ=WEEKLY;BYDAY=MO;BYHOUR=9;BYMINUTE=0`
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recurrence_rules: Vec<String>,
+}
+
+impl Recurrence {
+    /// Constructs a recurrence from RFC 5545 rules
+    pub fn rules(rules: Vec<&str>) -> Recurrence {
+        Recurrence {
+            recurrence_rules: rules.into_iter().map(String::from).collect(),
+            ..Recurrence::default()
+        }
+    }
+
+    /// sets the local date and time the recurrence starts
+    pub fn start(mut self, date_time: &str) -> Self {
+        self.start_date_time = Some(String::from(date_time));
+        self
+    }
+
+    /// sets the local date and time the recurrence ends
+    pub fn end(mut self, date_time: &str) -> Self {
+        self.end_date_time = Some(String::from(date_time));
+        self
+    }
+}
+
+/// What a reminder says, in one locale
+/// the text is also used for the push notification
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SpokenContent {
+    pub locale: String,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssml: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SpokenInfo {
+    #[serde(default)]
+    pub content: Vec<SpokenContent>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AlertInfo {
+    #[serde(rename = "spokenInfo")]
+    pub spoken_info: SpokenInfo,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PushNotification {
+    /// `ENABLED` or `DISABLED`
+    pub status: String,
+}
+
+/// A reminder to create or update
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderRequest {
+    /// set to the timestamp of the request by `RemindersClient` if empty
+    pub request_time: String,
+    pub trigger: Trigger,
+    pub alert_info: AlertInfo,
+    pub push_notification: PushNotification,
+}
+
+impl ReminderRequest {
+    fn new(trigger: Trigger) -> ReminderRequest {
+        ReminderRequest {
+            request_time: String::new(),
+            trigger,
+            alert_info: AlertInfo::default(),
+            push_notification: PushNotification {
+                status: String::from("ENABLED"),
+            },
+        }
+    }
+
+    /// Constructs a reminder for a local time, e.g. `2019-09-22T19:00:00.000`,
+    /// in the given time zone, e.g. `America/Los_Angeles`
+    pub fn absolute(scheduled_time: &str, time_zone_id: &str) -> ReminderRequest {
+        ReminderRequest::new(Trigger {
+            trigger_type: TriggerType::ScheduledAbsolute.to_string(),
+            scheduled_time: Some(String::from(scheduled_time)),
+            offset_in_seconds: None,
+            time_zone_id: Some(String::from(time_zone_id)),
+            recurrence: None,
+        })
+    }
+
+    /// Constructs a reminder for the given number of seconds from now
+    pub fn relative(offset_in_seconds: u64) -> ReminderRequest {
+        ReminderRequest::new(Trigger {
+            trigger_type: TriggerType::ScheduledRelative.to_string(),
+            scheduled_time: None,
+            offset_in_seconds: Some(offset_in_seconds),
+            time_zone_id: None,
+            recurrence: None,
+        })
+    }
+
+    /// sets how the reminder repeats
+    pub fn recurrence(mut self, recurrence: Recurrence) -> Self {
+        self.trigger.recurrence = Some(recurrence);
+        self
+    }
+
+    /// adds plain text content for a locale, e.g. `en-US`
+    pub fn content(mut self, locale: &str, text: &str) -> Self {
+        self.alert_info.spoken_info.content.push(SpokenContent {
+            locale: String::from(locale),
+            text: String::from(text),
+            ssml: None,
+        });
+        self
+    }
+
+    /// adds content for a locale with SSML for the spoken reminder
+    /// the text is used for the push notification
+    pub fn ssml_content(mut self, locale: &str, text: &str, ssml: &str) -> Self {
+        self.alert_info.spoken_info.content.push(SpokenContent {
+            locale: String::from(locale),
+            text: String::from(text),
+            ssml: Some(String::from(ssml)),
+        });
+        self
+    }
+
+    /// sets whether a push notification is sent to the Alexa app
+    pub fn push_notification(mut self, enabled: bool) -> Self {
+        self.push_notification.status = String::from(if enabled { "ENABLED" } else { "DISABLED" });
+        self
+    }
+
+    /// sets the time of the request
+    pub fn request_time(mut self, time: &str) -> Self {
+        self.request_time = String::from(time);
+        self
+    }
+}
+
+/// A reminder created by the skill
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Reminder {
+    pub alert_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_time: Option<String>,
+    /// `ON` or `COMPLETED`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub href: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<Trigger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_info: Option<AlertInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub push_notification: Option<PushNotification>,
+}
+
+/// The skill's reminders for the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<String>,
+    #[serde(default)]
+    pub alerts: Vec<Reminder>,
+}
+
+/// Client for the skill's reminders for the user of a request
+pub struct RemindersClient<T> {
+    client: ServiceClient<T>,
+    request_time: String,
+}
+
+impl<T: Transport> RemindersClient<T> {
+    /// Constructs a client using the given service client and request time
+    pub fn new(client: ServiceClient<T>, request_time: &str) -> RemindersClient<T> {
+        RemindersClient {
+            client,
+            request_time: String::from(request_time),
+        }
+    }
+
+    /// Constructs a client for the API endpoint, access token and timestamp of a request
+    pub fn from_request(req: &Request, transport: T) -> Result<RemindersClient<T>, Error> {
+        Ok(RemindersClient::new(
+            ServiceClient::from_request(req, transport)?,
+            &req.body.timestamp,
+        ))
+    }
+
+    /// creates a reminder
+    pub fn create(&self, reminder: &ReminderRequest) -> Result<Reminder, Error> {
+        self.client
+            .post("/v1/alerts/reminders", &self.with_time(reminder))
+            .map_err(|e| e.require_permissions(&[REMINDERS_SCOPE]))
+    }
+
+    /// retrieves a reminder
+    pub fn get(&self, alert_token: &str) -> Result<Reminder, Error> {
+        self.client
+            .get(&format!("/v1/alerts/reminders/{}", alert_token))
+            .map_err(|e| e.require_permissions(&[REMINDERS_SCOPE]))
+    }
+
+    /// replaces a reminder
+    pub fn update(&self, alert_token: &str, reminder: &ReminderRequest) -> Result<Reminder, Error> {
+        self.client
+            .put(
+                &format!("/v1/alerts/reminders/{}", alert_token),
+                &self.with_time(reminder),
+            )
+            .map_err(|e| e.require_permissions(&[REMINDERS_SCOPE]))
+    }
+
+    /// deletes a reminder
+    pub fn delete(&self, alert_token: &str) -> Result<(), Error> {
+        self.client
+            .delete(&format!("/v1/alerts/reminders/{}", alert_token))
+            .map_err(|e| e.require_permissions(&[REMINDERS_SCOPE]))
+    }
+
+    /// retrieves all of the skill's reminders for the user
+    pub fn list(&self) -> Result<ReminderList, Error> {
+        self.client
+            .get("/v1/alerts/reminders")
+            .map_err(|e| e.require_permissions(&[REMINDERS_SCOPE]))
+    }
+
+    fn with_time(&self, reminder: &ReminderRequest) -> ReminderRequest {
+        let mut reminder = reminder.clone();
+        if reminder.request_time.is_empty() {
+            reminder.request_time = self.request_time.clone();
+        }
+        reminder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockTransport;
+    use super::super::Method;
+    use super::*;
+    use crate::request_builder::RequestBuilder;
+
+    #[test]
+    fn test_reminders() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Method::Post,
+            "/v1/alerts/reminders",
+            200,
+            r#"{
+                "alertToken": "token1",
+                "createdTime": "2019-09-22T19:04:00.672Z",
+                "updatedTime": "2019-09-22T19:04:00.672Z",
+                "status": "ON",
+                "version": "1",
+                "href": "/v1/alerts/reminders/token1"
+            }"#,
+        );
+        mock.respond(
+            Method::Get,
+            "/v1/alerts/reminders",
+            200,
+            r#"{"totalCount": "1", "alerts": [{"alertToken": "token1", "status": "ON"}]}"#,
+        );
+        mock.respond(Method::Delete, "/v1/alerts/reminders/token1", 200, "");
+        let req = RequestBuilder::launch()
+            .timestamp("2019-09-22T19:04:00Z")
+            .api_access_token("token")
+            .build();
+        let client = RemindersClient::from_request(&req, &mock).unwrap();
+        let reminder = ReminderRequest::absolute("2019-09-22T19:00:00.000", "America/Los_Angeles")
+            .recurrence(Recurrence::rules(vec!["FREQ=DAILY;BYHOUR=19;BYMINUTE=0"]))
+            .content("en-US", "walk the dog")
+            .push_notification(false);
+        let created = client.create(&reminder).unwrap();
+        assert_eq!(created.alert_token, "token1");
+        assert_eq!(created.status, Some(String::from("ON")));
+
+        let sent = mock.last_request().unwrap();
+        let body: serde_json::Value = serde_json::from_str(&sent.body.unwrap()).unwrap();
+        assert_eq!(body["requestTime"], "2019-09-22T19:04:00Z");
+        assert_eq!(body["trigger"]["type"], "SCHEDULED_ABSOLUTE");
+        assert_eq!(body["trigger"]["timeZoneId"], "America/Los_Angeles");
+        assert_eq!(
+            body["trigger"]["recurrence"]["recurrenceRules"][0],
+            "FREQ=DAILY;BYHOUR=19;BYMINUTE=0"
+        );
+        assert_eq!(
+            body["alertInfo"]["spokenInfo"]["content"][0]["locale"],
+            "en-US"
+        );
+        assert_eq!(body["pushNotification"]["status"], "DISABLED");
+
+        assert_eq!(client.list().unwrap().alerts.len(), 1);
+        assert!(client.delete("token1").is_ok());
+    }
+
+    #[test]
+    fn test_permission() {
+        let mock = MockTransport::new();
+        mock.respond(Method::Post, "/v1/alerts/reminders", 401, "");
+        let client = ServiceClient::new("https://api.amazonalexa.com", "token", &mock);
+        let client = RemindersClient::new(client, "2019-09-22T19:04:00Z");
+        match client.create(&ReminderRequest::relative(3600)) {
+            Err(Error::Unauthorized(_)) => {}
+            r => panic!("unexpected result {:?}", r),
+        }
+        mock.respond(Method::Post, "/v1/alerts/reminders", 403, "");
+        let card = client
+            .create(&ReminderRequest::relative(3600))
+            .unwrap_err()
+            .permission_card()
+            .unwrap();
+        assert_eq!(
+            card.permissions(),
+            Some(&[String::from(REMINDERS_SCOPE)][..])
+        );
+    }
+}
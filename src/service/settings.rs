@@ -0,0 +1,159 @@
+//! Client for the [Alexa Settings API](https://developer.amazon.com/docs/smapi/alexa-settings-api-reference.html)
+//!
+//! With the `tz` feature, `Request::local_time` converts the timestamp of a request
+//! to the time zone of the device:
+//!
+//! ```rust,no_run
+//! # use alexa_sdk::service::settings::SettingsClient;
+//! # use alexa_sdk::request_builder::RequestBuilder;
+//! # use alexa_sdk::service::mock::MockTransport;
+//! # fn main() -> Result<(), alexa_sdk::service::Error> {
+//! # let req = RequestBuilder::launch().api_access_token("token").build();
+//! # let transport = MockTransport::new();
+//! let zone = SettingsClient::from_request(&req, transport)?.time_zone()?;
+//! # #[cfg(feature = "tz")]
+//! let now = req.local_time(&zone);
+//! # let _ = zone;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{device_id, Error, ServiceClient, Transport};
+use crate::request::Request;
+
+/// Units of distance set for a device
+#[derive(Debug, PartialEq)]
+pub enum DistanceUnits {
+    Metric,
+    Imperial,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for DistanceUnits {
+    fn from(s: &'a str) -> DistanceUnits {
+        match s {
+            "METRIC" => DistanceUnits::Metric,
+            "IMPERIAL" => DistanceUnits::Imperial,
+            _ => DistanceUnits::Other(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for DistanceUnits {
+    fn from(s: String) -> DistanceUnits {
+        DistanceUnits::from(s.as_str())
+    }
+}
+
+/// Unit of temperature set for a device
+#[derive(Debug, PartialEq)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for TemperatureUnit {
+    fn from(s: &'a str) -> TemperatureUnit {
+        match s {
+            "CELSIUS" => TemperatureUnit::Celsius,
+            "FAHRENHEIT" => TemperatureUnit::Fahrenheit,
+            _ => TemperatureUnit::Other(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for TemperatureUnit {
+    fn from(s: String) -> TemperatureUnit {
+        TemperatureUnit::from(s.as_str())
+    }
+}
+
+/// Client for the settings of the device a request came from
+pub struct SettingsClient<T> {
+    client: ServiceClient<T>,
+    device_id: String,
+}
+
+impl<T: Transport> SettingsClient<T> {
+    /// Constructs a client for the given device
+    pub fn new(client: ServiceClient<T>, device_id: &str) -> SettingsClient<T> {
+        SettingsClient {
+            client,
+            device_id: String::from(device_id),
+        }
+    }
+
+    /// Constructs a client for the device, API endpoint and access token of a request
+    pub fn from_request(req: &Request, transport: T) -> Result<SettingsClient<T>, Error> {
+        let device_id = device_id(req)?;
+        Ok(SettingsClient::new(
+            ServiceClient::from_request(req, transport)?,
+            device_id,
+        ))
+    }
+
+    /// retrieves the time zone of the device, e.g. `America/Los_Angeles`
+    pub fn time_zone(&self) -> Result<String, Error> {
+        self.get("System.timeZone")
+    }
+
+    /// retrieves the units of distance of the device
+    pub fn distance_units(&self) -> Result<DistanceUnits, Error> {
+        self.get("System.distanceUnits").map(DistanceUnits::from)
+    }
+
+    /// retrieves the unit of temperature of the device
+    pub fn temperature_unit(&self) -> Result<TemperatureUnit, Error> {
+        self.get("System.temperatureUnit")
+            .map(TemperatureUnit::from)
+    }
+
+    fn get(&self, setting: &str) -> Result<String, Error> {
+        let path = format!("/v2/devices/{}/settings/{}", self.device_id, setting);
+        self.client.get(&path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockTransport;
+    use super::super::Method;
+    use super::*;
+    use crate::request_builder::RequestBuilder;
+
+    #[test]
+    fn test_settings() {
+        let mock = MockTransport::new();
+        let path = "/v2/devices/dev1/settings/System.";
+        mock.respond(
+            Method::Get,
+            &format!("{}timeZone", path),
+            200,
+            r#""Europe/Berlin""#,
+        );
+        mock.respond(
+            Method::Get,
+            &format!("{}distanceUnits", path),
+            200,
+            r#""METRIC""#,
+        );
+        mock.respond(
+            Method::Get,
+            &format!("{}temperatureUnit", path),
+            200,
+            r#""FAHRENHEIT""#,
+        );
+        let req = RequestBuilder::launch()
+            .device_id("dev1")
+            .api_access_token("token")
+            .build();
+        let client = SettingsClient::from_request(&req, &mock).unwrap();
+        assert_eq!(client.time_zone().unwrap(), "Europe/Berlin");
+        assert_eq!(client.distance_units().unwrap(), DistanceUnits::Metric);
+        assert_eq!(
+            client.temperature_unit().unwrap(),
+            TemperatureUnit::Fahrenheit
+        );
+    }
+}
@@ -0,0 +1,432 @@
+//! Client for the [Timers API](https://developer.amazon.com/docs/smapi/alexa-timers-api-reference.html)
+//!
+//! ```rust,no_run
+//! # use alexa_sdk::service::timers::{TimerRequest, TimersClient};
+//! # use std::time::Duration;
+//! # use alexa_sdk::request_builder::RequestBuilder;
+//! # use alexa_sdk::service::mock::MockTransport;
+//! # fn main() -> Result<(), alexa_sdk::service::Error> {
+//! # let req = RequestBuilder::launch().api_access_token("token").build();
+//! # let transport = MockTransport::new();
+//! let timer = TimerRequest::announce(Duration::from_secs(600), "en-US", "The pasta is ready")
+//!     .label("pasta");
+//! let created = TimersClient::from_request(&req, transport)?.create(&timer)?;
+//! # let _ = created;
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate serde_derive;
+extern crate serde_json;
+
+use self::serde_derive::{Deserialize, Serialize};
+use super::{Error, Method, ServiceClient, Transport};
+use crate::request::Request;
+use std::fmt;
+use std::time::Duration;
+
+/// permission scope for reading and writing the skill's timers
+pub const TIMERS_SCOPE: &str = "alexa::alerts:timers:skill:readwrite";
+
+/// What happens when a timer goes off
+#[derive(Debug, PartialEq)]
+pub enum OperationType {
+    Announce,
+    LaunchTask,
+    NotifyOnly,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for OperationType {
+    fn from(s: &'a str) -> OperationType {
+        match s {
+            "ANNOUNCE" => OperationType::Announce,
+            "LAUNCH_TASK" => OperationType::LaunchTask,
+            "NOTIFY_ONLY" => OperationType::NotifyOnly,
+            _ => OperationType::Other(s.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            OperationType::Announce => "ANNOUNCE",
+            OperationType::LaunchTask => "LAUNCH_TASK",
+            OperationType::NotifyOnly => "NOTIFY_ONLY",
+            OperationType::Other(ref s) => s,
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Text in one locale
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LocalizedText {
+    pub locale: String,
+    pub text: String,
+}
+
+/// The skill task launched by a `LAUNCH_TASK` timer
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Task {
+    pub name: String,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    #[serde(rename = "type")]
+    pub operation_type: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub text_to_announce: Vec<LocalizedText>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub text_to_confirm: Vec<LocalizedText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<Task>,
+}
+
+impl Operation {
+    /// Extracts the operation type
+    pub fn operation_type(&self) -> OperationType {
+        OperationType::from(self.operation_type.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationConfig {
+    pub play_audible: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggeringBehavior {
+    pub operation: Operation,
+    pub notification_config: NotificationConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DisplayExperience {
+    /// `VISIBLE` or `HIDDEN`
+    pub visibility: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreationBehavior {
+    pub display_experience: DisplayExperience,
+}
+
+/// A timer to create
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerRequest {
+    /// ISO 8601 duration, e.g. `PT10M`
+    pub duration: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timer_label: Option<String>,
+    pub creation_behavior: CreationBehavior,
+    pub triggering_behavior: TriggeringBehavior,
+}
+
+impl TimerRequest {
+    fn new(duration: Duration, operation: Operation) -> TimerRequest {
+        TimerRequest {
+            duration: iso_duration(duration),
+            timer_label: None,
+            creation_behavior: CreationBehavior {
+                display_experience: DisplayExperience {
+                    visibility: String::from("VISIBLE"),
+                },
+            },
+            triggering_behavior: TriggeringBehavior {
+                operation,
+                notification_config: NotificationConfig { play_audible: true },
+            },
+        }
+    }
+
+    /// Constructs a timer that announces the text when it goes off
+    pub fn announce(duration: Duration, locale: &str, text: &str) -> TimerRequest {
+        TimerRequest::new(
+            duration,
+            Operation {
+                operation_type: OperationType::Announce.to_string(),
+                text_to_announce: vec![LocalizedText {
+                    locale: String::from(locale),
+                    text: String::from(text),
+                }],
+                text_to_confirm: Vec::new(),
+                task: None,
+            },
+        )
+    }
+
+    /// Constructs a timer that asks the user to confirm with the text, then launches
+    /// the skill task when it goes off
+    pub fn launch_task(duration: Duration, locale: &str, text: &str, task: Task) -> TimerRequest {
+        TimerRequest::new(
+            duration,
+            Operation {
+                operation_type: OperationType::LaunchTask.to_string(),
+                text_to_announce: Vec::new(),
+                text_to_confirm: vec![LocalizedText {
+                    locale: String::from(locale),
+                    text: String::from(text),
+                }],
+                task: Some(task),
+            },
+        )
+    }
+
+    /// Constructs a timer that only plays the notification when it goes off
+    pub fn notify_only(duration: Duration) -> TimerRequest {
+        TimerRequest::new(
+            duration,
+            Operation {
+                operation_type: OperationType::NotifyOnly.to_string(),
+                text_to_announce: Vec::new(),
+                text_to_confirm: Vec::new(),
+                task: None,
+            },
+        )
+    }
+
+    /// sets the label of the timer
+    pub fn label(mut self, label: &str) -> Self {
+        self.timer_label = Some(String::from(label));
+        self
+    }
+
+    /// adds announcement or confirmation text for another locale
+    pub fn text(mut self, locale: &str, text: &str) -> Self {
+        let text = LocalizedText {
+            locale: String::from(locale),
+            text: String::from(text),
+        };
+        let operation = &mut self.triggering_behavior.operation;
+        match operation.operation_type() {
+            OperationType::LaunchTask => operation.text_to_confirm.push(text),
+            _ => operation.text_to_announce.push(text),
+        }
+        self
+    }
+
+    /// sets whether the timer is shown on devices with screens while it runs
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.creation_behavior.display_experience.visibility =
+            String::from(if visible { "VISIBLE" } else { "HIDDEN" });
+        self
+    }
+
+    /// sets whether a sound plays when the timer goes off
+    pub fn play_audible(mut self, play: bool) -> Self {
+        self.triggering_behavior.notification_config.play_audible = play;
+        self
+    }
+}
+
+/// A timer created by the skill
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Timer {
+    pub id: String,
+    /// `ON`, `PAUSED` or `OFF`
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timer_label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_time_when_paused: Option<String>,
+}
+
+/// The skill's timers for the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerList {
+    #[serde(default)]
+    pub timers: Vec<Timer>,
+    #[serde(default)]
+    pub total_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+/// Client for the skill's timers for the user of a request
+pub struct TimersClient<T> {
+    client: ServiceClient<T>,
+}
+
+impl<T: Transport> TimersClient<T> {
+    /// Constructs a client using the given service client
+    pub fn new(client: ServiceClient<T>) -> TimersClient<T> {
+        TimersClient { client }
+    }
+
+    /// Constructs a client for the API endpoint and access token of a request
+    pub fn from_request(req: &Request, transport: T) -> Result<TimersClient<T>, Error> {
+        Ok(TimersClient::new(ServiceClient::from_request(
+            req, transport,
+        )?))
+    }
+
+    /// creates and starts a timer
+    pub fn create(&self, timer: &TimerRequest) -> Result<Timer, Error> {
+        self.client
+            .post("/v1/alerts/timers", timer)
+            .map_err(|e| e.require_permissions(&[TIMERS_SCOPE]))
+    }
+
+    /// retrieves a timer
+    pub fn get(&self, id: &str) -> Result<Timer, Error> {
+        self.client
+            .get(&format!("/v1/alerts/timers/{}", id))
+            .map_err(|e| e.require_permissions(&[TIMERS_SCOPE]))
+    }
+
+    /// retrieves all of the skill's timers for the user
+    pub fn list(&self) -> Result<TimerList, Error> {
+        self.client
+            .get("/v1/alerts/timers")
+            .map_err(|e| e.require_permissions(&[TIMERS_SCOPE]))
+    }
+
+    /// pauses a running timer
+    pub fn pause(&self, id: &str) -> Result<(), Error> {
+        self.action(&format!("/v1/alerts/timers/{}/pause", id))
+    }
+
+    /// resumes a paused timer
+    pub fn resume(&self, id: &str) -> Result<(), Error> {
+        self.action(&format!("/v1/alerts/timers/{}/resume", id))
+    }
+
+    /// cancels a timer
+    pub fn cancel(&self, id: &str) -> Result<(), Error> {
+        self.client
+            .delete(&format!("/v1/alerts/timers/{}", id))
+            .map_err(|e| e.require_permissions(&[TIMERS_SCOPE]))
+    }
+
+    /// cancels all of the skill's timers for the user
+    pub fn cancel_all(&self) -> Result<(), Error> {
+        self.client
+            .delete("/v1/alerts/timers")
+            .map_err(|e| e.require_permissions(&[TIMERS_SCOPE]))
+    }
+
+    fn action(&self, path: &str) -> Result<(), Error> {
+        self.client
+            .send(Method::Post, path, None)
+            .map(|_| ())
+            .map_err(|e| e.require_permissions(&[TIMERS_SCOPE]))
+    }
+}
+
+/// formats a duration as ISO 8601, e.g. `PT1H30M`
+fn iso_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    let (h, m, s) = (secs / 3600, secs / 60 % 60, secs % 60);
+    let mut out = String::from("PT");
+    if h > 0 {
+        out.push_str(&format!("{}H", h));
+    }
+    if m > 0 {
+        out.push_str(&format!("{}M", m));
+    }
+    if s > 0 || secs == 0 {
+        out.push_str(&format!("{}S", s));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockTransport;
+    use super::*;
+    use crate::request_builder::RequestBuilder;
+
+    #[test]
+    fn test_iso_duration() {
+        assert_eq!(iso_duration(Duration::from_secs(600)), "PT10M");
+        assert_eq!(iso_duration(Duration::from_secs(5430)), "PT1H30M30S");
+        assert_eq!(iso_duration(Duration::from_secs(0)), "PT0S");
+    }
+
+    #[test]
+    fn test_timers() {
+        let mock = MockTransport::new();
+        mock.respond(
+            Method::Post,
+            "/v1/alerts/timers",
+            200,
+            r#"{
+                "id": "timer1",
+                "status": "ON",
+                "duration": "PT10M",
+                "timerLabel": "pasta",
+                "triggerTime": "2019-09-22T19:14:00.000Z",
+                "createdTime": "2019-09-22T19:04:00.000Z",
+                "updatedTime": "2019-09-22T19:04:00.000Z"
+            }"#,
+        );
+        mock.respond(Method::Post, "/v1/alerts/timers/timer1/pause", 200, "");
+        mock.respond(Method::Post, "/v1/alerts/timers/timer1/resume", 403, "");
+        let req = RequestBuilder::launch().api_access_token("token").build();
+        let client = TimersClient::from_request(&req, &mock).unwrap();
+        let timer = TimerRequest::announce(Duration::from_secs(600), "en-US", "The pasta is ready")
+            .label("pasta")
+            .visible(false);
+        let created = client.create(&timer).unwrap();
+        assert_eq!(created.id, "timer1");
+        assert_eq!(created.timer_label, Some(String::from("pasta")));
+
+        let sent = mock.last_request().unwrap();
+        let body: serde_json::Value = serde_json::from_str(&sent.body.unwrap()).unwrap();
+        assert_eq!(body["duration"], "PT10M");
+        assert_eq!(
+            body["creationBehavior"]["displayExperience"]["visibility"],
+            "HIDDEN"
+        );
+        let operation = &body["triggeringBehavior"]["operation"];
+        assert_eq!(operation["type"], "ANNOUNCE");
+        assert_eq!(operation["textToAnnounce"][0]["text"], "The pasta is ready");
+
+        assert!(client.pause("timer1").is_ok());
+        let card = client
+            .resume("timer1")
+            .unwrap_err()
+            .permission_card()
+            .unwrap();
+        assert_eq!(card.permissions(), Some(&[String::from(TIMERS_SCOPE)][..]));
+    }
+
+    #[test]
+    fn test_launch_task() {
+        let task = Task {
+            name: String::from("amzn1.ask.skill.myappid.CheckOven"),
+            version: String::from("1"),
+            input: None,
+        };
+        let timer =
+            TimerRequest::launch_task(Duration::from_secs(90), "en-US", "Check the oven?", task)
+                .text("de-DE", "Ofen prüfen?");
+        let operation = &timer.triggering_behavior.operation;
+        assert_eq!(operation.operation_type(), OperationType::LaunchTask);
+        assert_eq!(operation.text_to_confirm.len(), 2);
+        assert_eq!(timer.duration, "PT1M30S");
+    }
+}
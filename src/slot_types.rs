@@ -0,0 +1,466 @@
+//! Typed values for Alexa [built-in slot types](https://developer.amazon.com/docs/custom-skills/slot-type-reference.html)
+//!
+//! Each type implements `FromStr`, so slot values can be converted with
+//! `Request::slot_as`, e.g. `req.slot_as::<AmazonDate>("when")`.
+
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Error returned when a slot value cannot be parsed as the requested type
+#[derive(Debug, PartialEq)]
+pub struct SlotParseError {
+    value: String,
+}
+
+impl SlotParseError {
+    fn new(value: &str) -> SlotParseError {
+        SlotParseError {
+            value: String::from(value),
+        }
+    }
+}
+
+impl fmt::Display for SlotParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid slot value: {}", self.value)
+    }
+}
+
+impl Error for SlotParseError {}
+
+/// Parses a date component, returning `None` for unspecified (`X`) components
+fn component<T: FromStr>(s: &str, full: &str) -> Result<Option<T>, SlotParseError> {
+    if !s.is_empty() && s.chars().all(|c| c == 'X') {
+        Ok(None)
+    } else if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) {
+        s.parse().map(Some).map_err(|_| SlotParseError::new(full))
+    } else {
+        Err(SlotParseError::new(full))
+    }
+}
+
+/// parses a component of a date, rejecting values outside the range
+fn ranged(s: &str, full: &str, range: RangeInclusive<u8>) -> Result<Option<u8>, SlotParseError> {
+    match component(s, full)? {
+        Some(v) if !range.contains(&v) => Err(SlotParseError::new(full)),
+        v => Ok(v),
+    }
+}
+
+/// returns the number of days in a month, allowing for February 29 if the year is unknown
+fn days_in_month(year: Option<u16>, month: u8) -> u8 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 => match year {
+            Some(y) if y % 4 != 0 || (y % 100 == 0 && y % 400 != 0) => 28,
+            _ => 29,
+        },
+        _ => 31,
+    }
+}
+
+/// parses the week of a date, e.g. `W48`
+fn week(w: &str, full: &str) -> Result<u8, SlotParseError> {
+    match w[1..].parse() {
+        Ok(week) if (1..=53).contains(&week) => Ok(week),
+        _ => Err(SlotParseError::new(full)),
+    }
+}
+
+/// Seasons, as returned for utterances like "this winter"
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Season {
+    Winter,
+    Spring,
+    Summer,
+    Fall,
+}
+
+impl FromStr for Season {
+    type Err = SlotParseError;
+
+    fn from_str(s: &str) -> Result<Season, SlotParseError> {
+        match s {
+            "WI" => Ok(Season::Winter),
+            "SP" => Ok(Season::Spring),
+            "SU" => Ok(Season::Summer),
+            "FA" => Ok(Season::Fall),
+            _ => Err(SlotParseError::new(s)),
+        }
+    }
+}
+
+/// `AMAZON.DATE` slot value
+#[derive(Debug, PartialEq, Clone)]
+pub enum AmazonDate {
+    /// a specific day, e.g. `2015-11-24`
+    Day { year: u16, month: u8, day: u8 },
+    /// a week, e.g. `2015-W48`
+    Week { year: u16, week: u8 },
+    /// a weekend, e.g. `2015-W49-WE`
+    Weekend { year: u16, week: u8 },
+    /// a month, e.g. `2015-11`
+    Month { year: u16, month: u8 },
+    /// a year, e.g. `2015`
+    Year { year: u16 },
+    /// a decade, given by its first year, e.g. `201X`
+    Decade { start: u16 },
+    /// a season, e.g. `2017-WI`
+    Season { year: u16, season: Season },
+    /// a date with unspecified components, e.g. `XXXX-11-25` or `2015-XX-XX`
+    Partial {
+        year: Option<u16>,
+        month: Option<u8>,
+        day: Option<u8>,
+    },
+    /// the present, e.g. "now"
+    PresentRef,
+}
+
+impl FromStr for AmazonDate {
+    type Err = SlotParseError;
+
+    fn from_str(s: &str) -> Result<AmazonDate, SlotParseError> {
+        if s == "PRESENT_REF" {
+            return Ok(AmazonDate::PresentRef);
+        }
+        let parts: Vec<&str> = s.split('-').collect();
+        match parts.as_slice() {
+            [y] if y.len() == 4 && y.ends_with('X') && !y.starts_with('X') => {
+                let decade: u16 = y[..3].parse().map_err(|_| SlotParseError::new(s))?;
+                Ok(AmazonDate::Decade { start: decade * 10 })
+            }
+            [y] if y.len() == 4 => match component(y, s)? {
+                Some(year) => Ok(AmazonDate::Year { year }),
+                None => Err(SlotParseError::new(s)),
+            },
+            [y, p] if p.parse::<Season>().is_ok() => Ok(AmazonDate::Season {
+                year: y.parse().map_err(|_| SlotParseError::new(s))?,
+                season: p.parse()?,
+            }),
+            [y, w] if w.starts_with('W') => Ok(AmazonDate::Week {
+                year: y.parse().map_err(|_| SlotParseError::new(s))?,
+                week: week(w, s)?,
+            }),
+            [y, w, "WE"] if w.starts_with('W') => Ok(AmazonDate::Weekend {
+                year: y.parse().map_err(|_| SlotParseError::new(s))?,
+                week: week(w, s)?,
+            }),
+            [y, m] => match (component(y, s)?, ranged(m, s, 1..=12)?) {
+                (Some(year), Some(month)) => Ok(AmazonDate::Month { year, month }),
+                (year, month) => Ok(AmazonDate::Partial {
+                    year,
+                    month,
+                    day: None,
+                }),
+            },
+            [y, m, d] => match (
+                component(y, s)?,
+                ranged(m, s, 1..=12)?,
+                ranged(d, s, 1..=31)?,
+            ) {
+                (year, Some(month), Some(day)) if day > days_in_month(year, month) => {
+                    Err(SlotParseError::new(s))
+                }
+                (Some(year), Some(month), Some(day)) => Ok(AmazonDate::Day { year, month, day }),
+                (year, month, day) => Ok(AmazonDate::Partial { year, month, day }),
+            },
+            _ => Err(SlotParseError::new(s)),
+        }
+    }
+}
+
+/// `AMAZON.TIME` slot value
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AmazonTime {
+    /// a specific time of day, e.g. `14:25`
+    Time {
+        hour: u8,
+        minute: u8,
+    },
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+}
+
+impl FromStr for AmazonTime {
+    type Err = SlotParseError;
+
+    fn from_str(s: &str) -> Result<AmazonTime, SlotParseError> {
+        match s {
+            "MO" => Ok(AmazonTime::Morning),
+            "AF" => Ok(AmazonTime::Afternoon),
+            "EV" => Ok(AmazonTime::Evening),
+            "NI" => Ok(AmazonTime::Night),
+            _ => {
+                let mut parts = s.splitn(2, ':');
+                let hour: u8 = match parts.next().map(str::parse) {
+                    Some(Ok(h)) if h < 24 => h,
+                    _ => return Err(SlotParseError::new(s)),
+                };
+                let minute: u8 = match parts.next().map(str::parse) {
+                    Some(Ok(m)) if m < 60 => m,
+                    _ => return Err(SlotParseError::new(s)),
+                };
+                Ok(AmazonTime::Time { hour, minute })
+            }
+        }
+    }
+}
+
+/// `AMAZON.DURATION` slot value, parsed from an ISO-8601 duration such as `PT2H30M`
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct AmazonDuration {
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+}
+
+impl AmazonDuration {
+    /// converts to a `std::time::Duration`
+    /// returns `None` if the duration contains years or months, which have no fixed length
+    pub fn to_std(&self) -> Option<Duration> {
+        if self.years > 0 || self.months > 0 {
+            return None;
+        }
+        let days = u64::from(self.weeks) * 7 + u64::from(self.days);
+        let secs = days * 86_400
+            + u64::from(self.hours) * 3_600
+            + u64::from(self.minutes) * 60
+            + u64::from(self.seconds);
+        Some(Duration::from_secs(secs))
+    }
+}
+
+impl FromStr for AmazonDuration {
+    type Err = SlotParseError;
+
+    fn from_str(s: &str) -> Result<AmazonDuration, SlotParseError> {
+        if !s.starts_with('P') || s.len() < 3 {
+            return Err(SlotParseError::new(s));
+        }
+        let mut d = AmazonDuration::default();
+        let mut in_time = false;
+        let mut num = String::new();
+        // units must appear at most once each, from largest to smallest
+        let mut last_unit = None;
+        for c in s[1..].chars() {
+            if c.is_ascii_digit() {
+                num.push(c);
+                continue;
+            }
+            if c == 'T' && !in_time && num.is_empty() {
+                in_time = true;
+                continue;
+            }
+            let n: u32 = num.parse().map_err(|_| SlotParseError::new(s))?;
+            num.clear();
+            let (unit, field) = match (in_time, c) {
+                (false, 'Y') => (0, &mut d.years),
+                (false, 'M') => (1, &mut d.months),
+                (false, 'W') => (2, &mut d.weeks),
+                (false, 'D') => (3, &mut d.days),
+                (true, 'H') => (4, &mut d.hours),
+                (true, 'M') => (5, &mut d.minutes),
+                (true, 'S') => (6, &mut d.seconds),
+                _ => return Err(SlotParseError::new(s)),
+            };
+            if last_unit.is_some_and(|last| unit <= last) {
+                return Err(SlotParseError::new(s));
+            }
+            last_unit = Some(unit);
+            *field = n;
+        }
+        if !num.is_empty() || s.ends_with('T') {
+            return Err(SlotParseError::new(s));
+        }
+        Ok(d)
+    }
+}
+
+/// `AMAZON.FOUR_DIGIT_NUMBER` slot value
+/// kept as a string to preserve leading zeros, e.g. `0042`
+#[derive(Debug, PartialEq, Clone)]
+pub struct FourDigitNumber(String);
+
+impl FourDigitNumber {
+    /// returns the digits as a string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// returns the numeric value
+    pub fn value(&self) -> u16 {
+        self.0.parse().unwrap_or(0)
+    }
+}
+
+impl FromStr for FourDigitNumber {
+    type Err = SlotParseError;
+
+    fn from_str(s: &str) -> Result<FourDigitNumber, SlotParseError> {
+        if s.len() == 4 && s.chars().all(|c| c.is_ascii_digit()) {
+            Ok(FourDigitNumber(String::from(s)))
+        } else {
+            Err(SlotParseError::new(s))
+        }
+    }
+}
+
+/// `AMAZON.Ordinal` slot value, e.g. `3` for "third"
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Ordinal(pub u32);
+
+impl FromStr for Ordinal {
+    type Err = SlotParseError;
+
+    fn from_str(s: &str) -> Result<Ordinal, SlotParseError> {
+        s.parse().map(Ordinal).map_err(|_| SlotParseError::new(s))
+    }
+}
+
+/// `AMAZON.NUMBER` slot value
+/// Alexa sends `?` when it heard a number it could not recognize
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AmazonNumber(pub i64);
+
+impl FromStr for AmazonNumber {
+    type Err = SlotParseError;
+
+    fn from_str(s: &str) -> Result<AmazonNumber, SlotParseError> {
+        s.parse()
+            .map(AmazonNumber)
+            .map_err(|_| SlotParseError::new(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date() {
+        assert_eq!(
+            "2015-11-24".parse(),
+            Ok(AmazonDate::Day {
+                year: 2015,
+                month: 11,
+                day: 24
+            })
+        );
+        assert_eq!(
+            "2019-W12-WE".parse(),
+            Ok(AmazonDate::Weekend {
+                year: 2019,
+                week: 12
+            })
+        );
+        assert_eq!(
+            "2019-W12".parse(),
+            Ok(AmazonDate::Week {
+                year: 2019,
+                week: 12
+            })
+        );
+        assert_eq!(
+            "2017-WI".parse(),
+            Ok(AmazonDate::Season {
+                year: 2017,
+                season: Season::Winter
+            })
+        );
+        assert_eq!(
+            "2015-11".parse(),
+            Ok(AmazonDate::Month {
+                year: 2015,
+                month: 11
+            })
+        );
+        assert_eq!("2015".parse(), Ok(AmazonDate::Year { year: 2015 }));
+        assert_eq!("201X".parse(), Ok(AmazonDate::Decade { start: 2010 }));
+        assert_eq!(
+            "2015-XX-XX".parse(),
+            Ok(AmazonDate::Partial {
+                year: Some(2015),
+                month: None,
+                day: None
+            })
+        );
+        assert_eq!(
+            "XXXX-11-25".parse(),
+            Ok(AmazonDate::Partial {
+                year: None,
+                month: Some(11),
+                day: Some(25)
+            })
+        );
+        assert_eq!("PRESENT_REF".parse(), Ok(AmazonDate::PresentRef));
+        assert!("tomorrow".parse::<AmazonDate>().is_err());
+        assert!("2015-13-45".parse::<AmazonDate>().is_err());
+        assert!("2015-11-32".parse::<AmazonDate>().is_err());
+        assert!("2019-02-31".parse::<AmazonDate>().is_err());
+        assert!("2019-04-31".parse::<AmazonDate>().is_err());
+        assert!("2019-02-29".parse::<AmazonDate>().is_err());
+        assert!("1900-02-29".parse::<AmazonDate>().is_err());
+        assert!("XXXX-02-30".parse::<AmazonDate>().is_err());
+        assert!("2020-02-29".parse::<AmazonDate>().is_ok());
+        assert!("2000-02-29".parse::<AmazonDate>().is_ok());
+        assert!("XXXX-02-29".parse::<AmazonDate>().is_ok());
+        assert!("2019-12-31".parse::<AmazonDate>().is_ok());
+        assert!("XXXX-00-25".parse::<AmazonDate>().is_err());
+        assert!("2015-00".parse::<AmazonDate>().is_err());
+        assert!("2015-W99".parse::<AmazonDate>().is_err());
+        assert!("2015-W0-WE".parse::<AmazonDate>().is_err());
+    }
+
+    #[test]
+    fn test_time() {
+        assert_eq!(
+            "14:25".parse(),
+            Ok(AmazonTime::Time {
+                hour: 14,
+                minute: 25
+            })
+        );
+        assert_eq!("MO".parse(), Ok(AmazonTime::Morning));
+        assert_eq!("NI".parse(), Ok(AmazonTime::Night));
+        assert!("25:00".parse::<AmazonTime>().is_err());
+    }
+
+    #[test]
+    fn test_duration() {
+        let d: AmazonDuration = "PT2H30M".parse().unwrap();
+        assert_eq!(d.hours, 2);
+        assert_eq!(d.minutes, 30);
+        assert_eq!(d.to_std(), Some(Duration::from_secs(9_000)));
+        let d: AmazonDuration = "P1Y2M10D".parse().unwrap();
+        assert_eq!(d.years, 1);
+        assert_eq!(d.months, 2);
+        assert_eq!(d.days, 10);
+        assert_eq!(d.to_std(), None);
+        assert!("PT".parse::<AmazonDuration>().is_err());
+        assert!("P2H".parse::<AmazonDuration>().is_err());
+        assert!("P1D1D".parse::<AmazonDuration>().is_err());
+        assert!("PT1S1H".parse::<AmazonDuration>().is_err());
+        assert!("P1D2M".parse::<AmazonDuration>().is_err());
+        assert!("PT1M1M".parse::<AmazonDuration>().is_err());
+        assert!("P1MT1M".parse::<AmazonDuration>().is_ok());
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!("42".parse(), Ok(AmazonNumber(42)));
+        assert!("?".parse::<AmazonNumber>().is_err());
+        assert_eq!("0042".parse::<FourDigitNumber>().unwrap().as_str(), "0042");
+        assert!("42".parse::<FourDigitNumber>().is_err());
+        assert_eq!("3".parse(), Ok(Ordinal(3)));
+    }
+}
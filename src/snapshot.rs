@@ -0,0 +1,290 @@
+//! Golden-file snapshot testing of skill responses
+//!
+//! Each snapshot is a pair of files in a directory: `<name>.request.json`, a recorded
+//! request, and `<name>.response.json`, the expected response. Checking runs every
+//! recorded request through the handler and diffs the serialized response against
+//! the stored one:
+//!
+//! ```rust,no_run
+//! # use alexa_sdk::request::Request;
+//! # use alexa_sdk::response::Response;
+//! # use alexa_sdk::snapshot::Snapshots;
+//! # fn my_handler(_req: &Request) -> Response {
+//! #     Response::end()
+//! # }
+//! // in a #[test] function
+//! Snapshots::new("tests/snapshots")
+//!     .ignore("token")
+//!     .assert(my_handler);
+//! ```
+//!
+//! Set `ALEXA_UPDATE_SNAPSHOTS=1` to record missing responses, and to re-record all
+//! responses after an intended change. Otherwise a missing response is an error.
+
+extern crate serde;
+extern crate serde_json;
+
+use self::serde::de::DeserializeOwned;
+use self::serde::Serialize;
+use self::serde_json::{Map, Value};
+use crate::request::Request;
+use crate::response::Response;
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const REQUEST_SUFFIX: &str = ".request.json";
+const RESPONSE_SUFFIX: &str = ".response.json";
+
+/// Errors checking snapshots
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Json(PathBuf, serde_json::Error),
+    /// responses that differ from their snapshots, by snapshot name, with a diff
+    Mismatch(Vec<(String, String)>),
+    /// the named snapshot has a request but no recorded response
+    Missing(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref e) => write!(f, "error reading snapshots: {}", e),
+            Error::Json(ref p, ref e) => write!(f, "error parsing {}: {}", p.display(), e),
+            Error::Mismatch(ref diffs) => {
+                for (name, diff) in diffs {
+                    writeln!(f, "snapshot {} does not match:", name)?;
+                    write!(f, "{}", diff)?;
+                }
+                Ok(())
+            }
+            Error::Missing(ref name) => write!(
+                f,
+                "snapshot {} has no response, set ALEXA_UPDATE_SNAPSHOTS=1 to record it",
+                name
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+/// A directory of recorded request/response pairs
+pub struct Snapshots {
+    dir: PathBuf,
+    ignored: Vec<String>,
+    update: bool,
+}
+
+impl Snapshots {
+    /// Constructs snapshots stored in the given directory
+    pub fn new<P: AsRef<Path>>(dir: P) -> Snapshots {
+        Snapshots {
+            dir: dir.as_ref().to_path_buf(),
+            ignored: Vec::new(),
+            update: env::var("ALEXA_UPDATE_SNAPSHOTS").is_ok_and(|v| v == "1"),
+        }
+    }
+
+    /// ignores object fields with the given key, at any depth, when comparing responses
+    pub fn ignore(mut self, key: &str) -> Self {
+        self.ignored.push(String::from(key));
+        self
+    }
+
+    /// sets whether to re-record all responses instead of comparing them
+    pub fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// records a request and the response to it as a named snapshot
+    pub fn record(&self, name: &str, req: &Request, res: &Response) -> Result<(), Error> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(format!("{}{}", name, REQUEST_SUFFIX));
+        write_json(&path, req)?;
+        let path = self.dir.join(format!("{}{}", name, RESPONSE_SUFFIX));
+        write_json(&path, res)
+    }
+
+    /// runs every recorded request through the handler and compares the responses
+    /// to the snapshots, or records the responses in update mode
+    pub fn check<F>(&self, mut handler: F) -> Result<(), Error>
+    where
+        F: FnMut(&Request) -> Response,
+    {
+        let mut mismatches = Vec::new();
+        for name in self.names()? {
+            let path = self.dir.join(format!("{}{}", name, REQUEST_SUFFIX));
+            let req: Request = read_json(&path)?;
+            let res = handler(&req);
+            let path = self.dir.join(format!("{}{}", name, RESPONSE_SUFFIX));
+            if self.update {
+                write_json(&path, &res)?;
+                continue;
+            }
+            if !path.exists() {
+                return Err(Error::Missing(name));
+            }
+            let expected: Value = read_json(&path)?;
+            let actual = serde_json::to_value(&res).map_err(|e| Error::Json(path, e))?;
+            let mut diff = String::new();
+            self.diff("", &expected, &actual, &mut diff);
+            if !diff.is_empty() {
+                mismatches.push((name, diff));
+            }
+        }
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Mismatch(mismatches))
+        }
+    }
+
+    /// checks the snapshots, panicking with a readable diff on mismatch
+    pub fn assert<F>(&self, handler: F)
+    where
+        F: FnMut(&Request) -> Response,
+    {
+        if let Err(e) = self.check(handler) {
+            panic!("{}", e);
+        }
+    }
+
+    fn names(&self) -> Result<Vec<String>, Error> {
+        let mut names = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let file_name = entry?.file_name();
+            if let Some(name) = file_name
+                .to_str()
+                .and_then(|f| f.strip_suffix(REQUEST_SUFFIX))
+            {
+                names.push(String::from(name));
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    fn diff(&self, path: &str, expected: &Value, actual: &Value, out: &mut String) {
+        match (expected, actual) {
+            (Value::Object(e), Value::Object(a)) => self.diff_objects(path, e, a, out),
+            (Value::Array(e), Value::Array(a)) if e.len() == a.len() => {
+                for (i, (ev, av)) in e.iter().zip(a).enumerate() {
+                    self.diff(&format!("{}/{}", path, i), ev, av, out);
+                }
+            }
+            _ if expected != actual => {
+                out.push_str(&format!("- {}: {}\n", path, expected));
+                out.push_str(&format!("+ {}: {}\n", path, actual));
+            }
+            _ => {}
+        }
+    }
+
+    fn diff_objects(
+        &self,
+        path: &str,
+        expected: &Map<String, Value>,
+        actual: &Map<String, Value>,
+        out: &mut String,
+    ) {
+        let mut keys: Vec<&String> = expected.keys().chain(actual.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        for key in keys {
+            if self.ignored.contains(key) {
+                continue;
+            }
+            let p = format!("{}/{}", path, key);
+            match (expected.get(key), actual.get(key)) {
+                (Some(e), Some(a)) => self.diff(&p, e, a, out),
+                (Some(e), None) => out.push_str(&format!("- {}: {}\n", p, e)),
+                (None, Some(a)) => out.push_str(&format!("+ {}: {}\n", p, a)),
+                (None, None) => {}
+            }
+        }
+    }
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, Error> {
+    let s = fs::read_to_string(path)?;
+    serde_json::from_str(&s).map_err(|e| Error::Json(path.to_path_buf(), e))
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), Error> {
+    let s = serde_json::to_string_pretty(value).map_err(|e| Error::Json(path.to_path_buf(), e))?;
+    fs::write(path, s + "\n")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request_builder::RequestBuilder;
+    use crate::response::{Directive, Speech};
+
+    fn handler(_req: &Request) -> Response {
+        // a freshly generated id stands in for a volatile token
+        let token = RequestBuilder::launch().build().body.request_id;
+        Response::new(false)
+            .speech(Speech::plain("hello"))
+            .directive(Directive::new("Dialog.Delegate").field("token", Value::String(token)))
+    }
+
+    #[test]
+    fn test_snapshots() {
+        let dir = env::temp_dir().join(format!("alexa_sdk_snapshots_{}", std::process::id()));
+        let snapshots = Snapshots::new(&dir).update(false).ignore("token");
+        let req = RequestBuilder::launch().build();
+        snapshots.record("launch", &req, &handler(&req)).unwrap();
+        snapshots.check(handler).unwrap();
+
+        let changed = |_: &Request| Response::new(true).speech(Speech::plain("goodbye"));
+        match snapshots.check(changed) {
+            Err(Error::Mismatch(diffs)) => {
+                assert_eq!(diffs[0].0, "launch");
+                assert!(diffs[0]
+                    .1
+                    .contains("- /response/outputSpeech/text: \"hello\""));
+                assert!(diffs[0]
+                    .1
+                    .contains("+ /response/outputSpeech/text: \"goodbye\""));
+                assert!(diffs[0].1.contains("- /response/directives"));
+            }
+            r => panic!("unexpected result {:?}", r),
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_snapshot() {
+        let dir = env::temp_dir().join(format!("alexa_sdk_missing_{}", std::process::id()));
+        let snapshots = Snapshots::new(&dir).update(false).ignore("token");
+        let req = RequestBuilder::launch().build();
+        snapshots.record("launch", &req, &handler(&req)).unwrap();
+        let response = dir.join(format!("launch{}", RESPONSE_SUFFIX));
+        fs::remove_file(&response).unwrap();
+        match snapshots.check(handler) {
+            Err(Error::Missing(name)) => assert_eq!(name, "launch"),
+            r => panic!("unexpected result {:?}", r),
+        }
+        assert!(!response.exists());
+
+        let snapshots = snapshots.update(true);
+        snapshots.check(handler).unwrap();
+        assert!(response.exists());
+        snapshots.update(false).check(handler).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
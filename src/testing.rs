@@ -0,0 +1,317 @@
+//! Conversation test harness for driving a skill handler with synthetic requests
+//!
+//! A `Conversation` builds requests, passes them to the handler, and carries
+//! session attributes from each response into the next request, starting a new
+//! session whenever a response ends the current one.
+//!
+//! ```rust
+//! # use alexa_sdk::request::Request;
+//! # use alexa_sdk::response::{Response, Speech};
+//! # use alexa_sdk::testing::Conversation;
+//! # fn my_handler(req: &Request) -> Response {
+//! #     match req.slot_value("name") {
+//! #         Some(Some(name)) => Response::end().speech(Speech::plain(&format!("hello {}", name))),
+//! #         _ => Response::new(false).speech(Speech::plain("welcome")),
+//! #     }
+//! # }
+//! let mut c = Conversation::new(my_handler);
+//! c.launch().assert_speech_contains("welcome").assert_not_ended();
+//! c.intent("hello", &[("name", "bob")])
+//!     .assert_speech("hello bob")
+//!     .assert_ended();
+//! ```
+
+use crate::request::{Locale, Request};
+use crate::request_builder::RequestBuilder;
+use crate::response::Response;
+use std::collections::HashMap;
+
+/// Drives a skill handler through a multi-turn conversation
+pub struct Conversation<F> {
+    handler: F,
+    locale: Locale,
+    application_id: String,
+    user_id: String,
+    device_id: String,
+    session_id: String,
+    new_session: bool,
+    attributes: Option<HashMap<String, String>>,
+    turn: usize,
+}
+
+impl<F> Conversation<F>
+where
+    F: FnMut(&Request) -> Response,
+{
+    /// Constructs a conversation with the given handler, in the `en-US` locale
+    pub fn new(handler: F) -> Conversation<F> {
+        Conversation {
+            handler,
+            locale: Locale::AmericanEnglish,
+            application_id: String::from("amzn1.ask.skill.test"),
+            user_id: String::from("amzn1.ask.account.test"),
+            device_id: String::from("amzn1.ask.device.test"),
+            session_id: String::from("amzn1.echo-api.session.0"),
+            new_session: true,
+            attributes: None,
+            turn: 0,
+        }
+    }
+
+    /// sets the locale of subsequent requests
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// sets the user ID of subsequent requests
+    pub fn user_id(mut self, user_id: &str) -> Self {
+        self.user_id = String::from(user_id);
+        self
+    }
+
+    /// sends a `LaunchRequest`
+    pub fn launch(&mut self) -> Reply {
+        let req = self.builder(RequestBuilder::launch()).build();
+        self.send_raw(req)
+    }
+
+    /// sends an `IntentRequest` for the named intent with the given slot values
+    pub fn intent(&mut self, name: &str, slots: &[(&str, &str)]) -> Reply {
+        let req = slots
+            .iter()
+            .fold(RequestBuilder::intent(name), |b, (k, v)| b.slot(k, v));
+        let req = self.builder(req).build();
+        self.send_raw(req)
+    }
+
+    /// sends a `SessionEndedRequest` with the given reason, e.g. `USER_INITIATED`
+    pub fn session_ended(&mut self, reason: &str) -> Reply {
+        let req = self.builder(RequestBuilder::session_ended(reason)).build();
+        self.send_raw(req)
+    }
+
+    /// sends a request built with `RequestBuilder`, threading the conversation
+    /// session, user and locale into it
+    pub fn send_builder(&mut self, builder: RequestBuilder) -> Reply {
+        let req = self.builder(builder).build();
+        self.send_raw(req)
+    }
+
+    /// sends a request, replacing its session with the conversation session
+    /// and attributes
+    pub fn send_request(&mut self, mut req: Request) -> Reply {
+        req.session = self.builder(RequestBuilder::launch()).build().session;
+        self.send_raw(req)
+    }
+
+    /// sends a request as is, without threading the conversation session into it
+    /// the reply still carries over to the next request, as with `send_request`
+    pub fn send_raw(&mut self, req: Request) -> Reply {
+        let res = (self.handler)(&req);
+        let reply = Reply::new(res);
+        self.turn += 1;
+        if reply.is_ended() || req.body.reqtype == "SessionEndedRequest" {
+            self.new_session = true;
+            self.attributes = None;
+            self.session_id = format!("amzn1.echo-api.session.{}", self.turn);
+        } else {
+            self.new_session = false;
+            self.attributes = reply.attributes();
+        }
+        reply
+    }
+
+    fn builder(&self, builder: RequestBuilder) -> RequestBuilder {
+        builder
+            .request_id(&format!("amzn1.echo-api.request.{}", self.turn))
+            .locale(self.locale.clone())
+            .application_id(&self.application_id)
+            .user_id(&self.user_id)
+            .device_id(&self.device_id)
+            .session_id(&self.session_id)
+            .new_session(self.new_session)
+            .attributes(self.attributes.clone())
+    }
+}
+
+/// A handler response, with assertions on its contents
+/// assertions panic with a description of the mismatch
+pub struct Reply {
+    response: Response,
+}
+
+impl Reply {
+    fn new(response: Response) -> Reply {
+        Reply { response }
+    }
+
+    /// returns the response returned by the handler
+    pub fn response(&self) -> &Response {
+        &self.response
+    }
+
+    fn attributes(&self) -> Option<HashMap<String, String>> {
+        self.response.session_attributes().cloned()
+    }
+
+    fn is_ended(&self) -> bool {
+        self.response.should_end_session()
+    }
+
+    /// asserts the output speech text (or SSML) equals `expected`
+    pub fn assert_speech(&self, expected: &str) -> &Self {
+        assert_eq!(self.response.speech_text(), Some(expected), "speech");
+        self
+    }
+
+    /// asserts the output speech text (or SSML) contains `expected`
+    pub fn assert_speech_contains(&self, expected: &str) -> &Self {
+        let speech = self.response.speech_text().unwrap_or_default();
+        assert!(
+            speech.contains(expected),
+            "speech {:?} does not contain {:?}",
+            speech,
+            expected
+        );
+        self
+    }
+
+    /// asserts the reprompt text (or SSML) contains `expected`
+    pub fn assert_reprompt_contains(&self, expected: &str) -> &Self {
+        let reprompt = self.response.reprompt_text().unwrap_or_default();
+        assert!(
+            reprompt.contains(expected),
+            "reprompt {:?} does not contain {:?}",
+            reprompt,
+            expected
+        );
+        self
+    }
+
+    /// asserts the response has a card with the given title
+    pub fn assert_card_title(&self, expected: &str) -> &Self {
+        let title = self.response.body().card().and_then(|c| c.title());
+        assert_eq!(title, Some(expected), "card title");
+        self
+    }
+
+    /// asserts the response card content (or text, for standard cards) contains `expected`
+    pub fn assert_card_contains(&self, expected: &str) -> &Self {
+        let content = self
+            .response
+            .body()
+            .card()
+            .and_then(|c| c.content().or_else(|| c.text()))
+            .unwrap_or_default();
+        assert!(
+            content.contains(expected),
+            "card {:?} does not contain {:?}",
+            content,
+            expected
+        );
+        self
+    }
+
+    /// asserts the response includes a directive of the given type
+    pub fn assert_directive(&self, directive_type: &str) -> &Self {
+        let types: Vec<&str> = self
+            .response
+            .directives()
+            .iter()
+            .map(|d| d.directive_type())
+            .collect();
+        assert!(
+            types.contains(&directive_type),
+            "directives {:?} do not include {:?}",
+            types,
+            directive_type
+        );
+        self
+    }
+
+    /// asserts the session attribute with the given key has the given value
+    pub fn assert_attribute(&self, key: &str, expected: &str) -> &Self {
+        assert_eq!(
+            self.response.attribute(key),
+            Some(expected),
+            "attribute {}",
+            key
+        );
+        self
+    }
+
+    /// asserts the response ends the session
+    pub fn assert_ended(&self) -> &Self {
+        assert!(self.is_ended(), "expected the session to end");
+        self
+    }
+
+    /// asserts the response keeps the session open
+    pub fn assert_not_ended(&self) -> &Self {
+        assert!(!self.is_ended(), "expected the session to stay open");
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::ReqType;
+    use crate::response::{Directive, Speech};
+
+    fn counter(req: &Request) -> Response {
+        match req.reqtype() {
+            ReqType::LaunchRequest => {
+                let mut res = Response::new(false)
+                    .speech(Speech::plain("welcome"))
+                    .reprompt(Speech::plain("say count"));
+                res.add_attribute("count", "0");
+                res
+            }
+            _ if req.slot_value("stop").is_some() => Response::end(),
+            _ => {
+                let n: u32 = req
+                    .attribute_value("count")
+                    .and_then(|c| c.parse().ok())
+                    .unwrap_or(0);
+                let speech = format!("count is {}, new is {}", n + 1, req.is_new());
+                let mut res = Response::new(false)
+                    .speech(Speech::plain(&speech))
+                    .directive(Directive::new("Dialog.ElicitSlot"));
+                res.add_attribute("count", &(n + 1).to_string());
+                res
+            }
+        }
+    }
+
+    #[test]
+    fn test_conversation() {
+        let mut c = Conversation::new(counter);
+        c.launch()
+            .assert_speech("welcome")
+            .assert_reprompt_contains("count")
+            .assert_not_ended();
+        c.intent("count", &[])
+            .assert_speech("count is 1, new is false");
+        c.intent("count", &[])
+            .assert_speech_contains("count is 2")
+            .assert_attribute("count", "2")
+            .assert_directive("Dialog.ElicitSlot");
+        c.intent("count", &[("stop", "yes")]).assert_ended();
+        c.intent("count", &[])
+            .assert_speech("count is 1, new is true");
+    }
+
+    #[test]
+    fn test_send_request() {
+        let mut c = Conversation::new(counter);
+        c.launch();
+        c.send_request(RequestBuilder::intent("count").build())
+            .assert_speech("count is 1, new is false");
+        c.send_raw(RequestBuilder::intent("count").build())
+            .assert_speech("count is 1, new is true");
+        c.intent("count", &[])
+            .assert_speech("count is 2, new is false");
+    }
+}
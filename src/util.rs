@@ -0,0 +1,101 @@
+//! Internal helpers for generated IDs, timestamps and randomness
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// returns a random number, from the randomly seeded std hasher
+/// not suitable for cryptographic use
+pub(crate) fn random() -> u64 {
+    let mut h = RandomState::new().build_hasher();
+    h.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0),
+    );
+    h.finish()
+}
+
+/// returns a random index less than len, or 0 if len is 0
+pub(crate) fn random_index(len: usize) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    (random() % len as u64) as usize
+}
+
+/// generates a random ID in UUID format
+pub(crate) fn generate_id() -> String {
+    let (a, b) = (random(), random());
+    format!(
+        "{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+        a >> 32,
+        (a >> 16) & 0xffff,
+        a & 0x0fff,
+        (b >> 48) & 0x3fff | 0x8000,
+        b & 0xffff_ffff_ffff
+    )
+}
+
+/// returns the current time in the ISO-8601 format used by Alexa, e.g. `2019-01-01T00:00:00Z`
+pub(crate) fn timestamp_now() -> String {
+    timestamp_at(SystemTime::now())
+}
+
+/// returns the time in the ISO-8601 format used by Alexa
+pub(crate) fn timestamp_at(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (days, rem) = ((secs / 86_400) as i64, secs % 86_400);
+    // civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_generate_id() {
+        let id = generate_id();
+        assert_eq!(id.len(), 36);
+        assert_eq!(&id[14..15], "4");
+        assert_ne!(generate_id(), id);
+    }
+
+    #[test]
+    fn test_random_index() {
+        assert_eq!(random_index(0), 0);
+        assert!((0..100).all(|_| random_index(3) < 3));
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let ts = timestamp_now();
+        assert!(ts.ends_with('Z'));
+        assert!(ts.as_str() > "2019-01-01T00:00:00Z");
+        let t = UNIX_EPOCH + Duration::from_secs(1_569_178_800);
+        assert_eq!(timestamp_at(t), "2019-09-22T19:00:00Z");
+    }
+}